@@ -0,0 +1,33 @@
+use std::env;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    // Declare the `--cfg debug_unwraps="..."` override so it passes `check-cfg`
+    println!("cargo:rustc-check-cfg=cfg(debug_unwraps, values(\"check\", \"unchecked\"))");
+    println!("cargo:rustc-check-cfg=cfg(debug_unwraps_checks)");
+    println!("cargo:rustc-check-cfg=cfg(debug_unwraps_state)");
+
+    let feature = |name: &str| env::var_os(format!("CARGO_FEATURE_{}", name)).is_some();
+
+    // Whether the checks are performed when nothing chooses at runtime. By
+    // default this follows `debug_assertions`, which the `always-check` and
+    // `never-check` features override. Both are in turn overridden by passing
+    // `--cfg debug_unwraps="check"` or `--cfg debug_unwraps="unchecked"`.
+    let checks = match env::var("CARGO_CFG_DEBUG_UNWRAPS").as_deref() {
+        Ok("check") => true,
+        Ok("unchecked") => false,
+        _ => {
+            feature("ALWAYS_CHECK")
+                || (env::var_os("CARGO_CFG_DEBUG_ASSERTIONS").is_some() && !feature("NEVER_CHECK"))
+        }
+    };
+    if checks {
+        println!("cargo:rustc-cfg=debug_unwraps_checks");
+    }
+
+    // Whether types need to keep the state for their checks, which is also
+    // the case when the checks may be enabled at runtime
+    if checks || feature("RUNTIME_SWITCH") || feature("SCOPED_CHECKS") {
+        println!("cargo:rustc-cfg=debug_unwraps_state");
+    }
+}
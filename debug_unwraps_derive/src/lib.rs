@@ -0,0 +1,158 @@
+//! Derive macros for the `debug_unwraps` crate
+//!
+//! These are re-exported by `debug_unwraps` when its `derive` feature is
+//! enabled and should be used through that crate.
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, LitStr, Path};
+
+/// Integer types accepted in `#[repr()]`
+const REPR_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// Derives `debug_from_repr_unchecked()` for a fieldless enum with an integer
+/// `#[repr()]`.
+///
+/// The generated function converts a value of the `#[repr()]` type back to the
+/// enum. When debug assertions are enabled it will panic if the value is not
+/// the discriminant of any variant, otherwise it is a plain `transmute`.
+///
+/// ```
+/// #[derive(debug_unwraps::DebugFromRepr)]
+/// #[repr(u8)]
+/// enum Tag {
+///     Int = 1,
+///     Float = 2,
+/// }
+///
+/// # let bytes = [2];
+/// // SAFETY: The tag was written from a valid `Tag`
+/// let tag = unsafe { Tag::debug_from_repr_unchecked(bytes[0]) };
+/// # assert!(matches!(tag, Tag::Float));
+/// ```
+///
+/// The generated code refers to `::debug_unwraps`. If the crate is renamed or
+/// re-exported, pass its path with `#[debug_unwraps(crate = "...")]`.
+#[proc_macro_derive(DebugFromRepr, attributes(debug_unwraps))]
+pub fn derive_debug_from_repr(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    debug_from_repr(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn debug_from_repr(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "`DebugFromRepr` can only be derived for enums",
+            ))
+        }
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`DebugFromRepr` cannot be derived for generic enums",
+        ));
+    }
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "`DebugFromRepr` can only be derived for enums without fields",
+            ));
+        }
+    }
+    let repr = repr_type(input)?;
+    let krate = crate_path(input)?;
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let variants = data.variants.iter().map(|variant| &variant.ident);
+    let doc = format!(
+        "Converts a discriminant back to a `{}` without checking that it is \
+         valid only in Release mode.\n\n\
+         # Panics\n\
+         When debug assertions are enabled this function will panic if \
+         `value` is not the discriminant of any variant.\n\n\
+         # Safety\n\
+         Passing a value which is not the discriminant of any variant is \
+         undefined behavior when debug assertions are disabled.",
+        name
+    );
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #name {
+            #[doc = #doc]
+            #[inline]
+            #[track_caller]
+            #vis unsafe fn debug_from_repr_unchecked(value: #repr) -> Self {
+                if #krate::__private::checks_enabled()
+                    && !(false #(|| value == Self::#variants as #repr)*)
+                {
+                    #krate::__private::fail(
+                        #krate::FailureKind::Precondition,
+                        ::core::format_args!(
+                            "{} is not a discriminant of `{}`",
+                            value,
+                            ::core::stringify!(#name),
+                        ),
+                    )
+                }
+                ::core::mem::transmute::<#repr, Self>(value)
+            }
+        }
+    })
+}
+
+/// Finds the integer type given in the `#[repr()]` of the enum
+fn repr_type(input: &DeriveInput) -> Result<Ident, Error> {
+    let mut repr = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                if REPR_TYPES.iter().any(|ty| ident == ty) {
+                    repr = Some(ident.clone());
+                }
+            }
+            Ok(())
+        })?;
+    }
+    repr.ok_or_else(|| {
+        Error::new_spanned(
+            &input.ident,
+            "`DebugFromRepr` requires an integer `#[repr()]` such as `#[repr(u8)]`",
+        )
+    })
+}
+
+/// Finds the path to `debug_unwraps` given in `#[debug_unwraps(crate = "...")]`
+fn crate_path(input: &DeriveInput) -> Result<Path, Error> {
+    let mut path = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("debug_unwraps"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                path = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `debug_unwraps` attribute"))
+            }
+        })?;
+    }
+    Ok(path.unwrap_or_else(|| syn::parse_quote!(::debug_unwraps)))
+}
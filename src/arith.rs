@@ -0,0 +1,167 @@
+use crate::failure::{fail, FailureKind};
+use crate::mode::checks_enabled;
+
+/// Extension trait providing debug only checking of integer arithmetic
+pub trait DebugArithExt: Sized {
+    /// Adds `rhs` without checking for overflow only in Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if the
+    /// addition overflows.
+    ///
+    /// # Safety
+    /// Overflowing the addition is undefined behavior when debug assertions
+    /// are disabled.
+    unsafe fn debug_add_unchecked(self, rhs: Self) -> Self;
+
+    /// Subtracts `rhs` without checking for overflow only in Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if the
+    /// subtraction overflows.
+    ///
+    /// # Safety
+    /// Overflowing the subtraction is undefined behavior when debug
+    /// assertions are disabled.
+    unsafe fn debug_sub_unchecked(self, rhs: Self) -> Self;
+
+    /// Multiplies by `rhs` without checking for overflow only in Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if the
+    /// multiplication overflows.
+    ///
+    /// # Safety
+    /// Overflowing the multiplication is undefined behavior when debug
+    /// assertions are disabled.
+    unsafe fn debug_mul_unchecked(self, rhs: Self) -> Self;
+
+    /// Shifts left by `rhs` bits without checking the shift amount only in
+    /// Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if `rhs`
+    /// is not less than the number of bits in `Self`.
+    ///
+    /// # Safety
+    /// Shifting by `rhs >= Self::BITS` is undefined behavior when debug
+    /// assertions are disabled.
+    unsafe fn debug_shl_unchecked(self, rhs: u32) -> Self;
+
+    /// Shifts right by `rhs` bits without checking the shift amount only in
+    /// Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if `rhs`
+    /// is not less than the number of bits in `Self`.
+    ///
+    /// # Safety
+    /// Shifting by `rhs >= Self::BITS` is undefined behavior when debug
+    /// assertions are disabled.
+    unsafe fn debug_shr_unchecked(self, rhs: u32) -> Self;
+
+    /// Divides by `rhs` without checking for a zero divisor or overflow only
+    /// in Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if `rhs`
+    /// is zero or the division overflows.
+    ///
+    /// # Safety
+    /// Dividing by zero or overflowing the division (`MIN / -1`) is undefined
+    /// behavior when debug assertions are disabled.
+    unsafe fn debug_div_unchecked(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_debug_arith {
+    ($($t:ty)*) => {$(
+        impl DebugArithExt for $t {
+            #[inline]
+            #[track_caller]
+            unsafe fn debug_add_unchecked(self, rhs: Self) -> Self {
+                if checks_enabled() {
+                    match self.checked_add(rhs) {
+                        Some(value) => value,
+                        None => fail(FailureKind::Precondition, format_args!("attempt to add with overflow")),
+                    }
+                } else {
+                    self.unchecked_add(rhs)
+                }
+            }
+
+            #[inline]
+            #[track_caller]
+            unsafe fn debug_sub_unchecked(self, rhs: Self) -> Self {
+                if checks_enabled() {
+                    match self.checked_sub(rhs) {
+                        Some(value) => value,
+                        None => fail(FailureKind::Precondition, format_args!("attempt to subtract with overflow")),
+                    }
+                } else {
+                    self.unchecked_sub(rhs)
+                }
+            }
+
+            #[inline]
+            #[track_caller]
+            unsafe fn debug_mul_unchecked(self, rhs: Self) -> Self {
+                if checks_enabled() {
+                    match self.checked_mul(rhs) {
+                        Some(value) => value,
+                        None => fail(FailureKind::Precondition, format_args!("attempt to multiply with overflow")),
+                    }
+                } else {
+                    self.unchecked_mul(rhs)
+                }
+            }
+
+            #[inline]
+            #[track_caller]
+            unsafe fn debug_shl_unchecked(self, rhs: u32) -> Self {
+                if checks_enabled() {
+                    match self.checked_shl(rhs) {
+                        Some(value) => value,
+                        None => fail(FailureKind::Precondition, format_args!("attempt to shift left with overflow")),
+                    }
+                } else {
+                    // `unchecked_shl` is too recent for the supported compilers,
+                    // but the check of `checked_shl` is optimized out the same
+                    self.checked_shl(rhs).unwrap_unchecked()
+                }
+            }
+
+            #[inline]
+            #[track_caller]
+            unsafe fn debug_shr_unchecked(self, rhs: u32) -> Self {
+                if checks_enabled() {
+                    match self.checked_shr(rhs) {
+                        Some(value) => value,
+                        None => fail(FailureKind::Precondition, format_args!("attempt to shift right with overflow")),
+                    }
+                } else {
+                    // `unchecked_shr` is too recent for the supported compilers,
+                    // but the check of `checked_shr` is optimized out the same
+                    self.checked_shr(rhs).unwrap_unchecked()
+                }
+            }
+
+            #[inline]
+            #[track_caller]
+            unsafe fn debug_div_unchecked(self, rhs: Self) -> Self {
+                if checks_enabled() {
+                    match self.checked_div(rhs) {
+                        Some(value) => value,
+                        None if rhs == 0 => fail(FailureKind::Precondition, format_args!("attempt to divide by zero")),
+                        None => fail(FailureKind::Precondition, format_args!("attempt to divide with overflow")),
+                    }
+                } else {
+                    // `checked_div` only fails on the two cases the caller
+                    // promised cannot happen, so both checks are optimized out
+                    self.checked_div(rhs).unwrap_unchecked()
+                }
+            }
+        }
+    )*};
+}
+
+impl_debug_arith!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
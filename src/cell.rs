@@ -0,0 +1,196 @@
+use core::cell::{Cell, UnsafeCell};
+use core::ops::{Deref, DerefMut};
+use core::panic::Location;
+
+use crate::failure::{fail, FailureKind};
+use crate::mode::{checks_enabled, CheckState};
+
+/// A mutable memory location with `RefCell` style borrow checking only in
+/// Debug mode.
+///
+/// When debug assertions are disabled this is an `UnsafeCell<T>` with the
+/// same size and cost, unless the `runtime-switch` or `scoped-checks` features
+/// require tracking anyway. Borrowing is therefore `unsafe`, as the caller
+/// has to guarantee that borrows do not conflict.
+pub struct DebugCell<T: ?Sized> {
+    state: CheckState<BorrowState>,
+    value: UnsafeCell<T>,
+}
+
+/// Borrows of a `DebugCell` which are currently alive
+struct BorrowState {
+    /// Number of shared borrows, or -1 while mutably borrowed
+    borrows: Cell<isize>,
+    /// Location of the first borrow which is still alive
+    location: Cell<Option<&'static Location<'static>>>,
+}
+
+impl<T> DebugCell<T> {
+    /// Creates a new `DebugCell` containing `value`
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self {
+            state: CheckState::new(BorrowState {
+                borrows: Cell::new(0),
+                location: Cell::new(None),
+            }),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Consumes the `DebugCell`, returning the wrapped value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: ?Sized> DebugCell<T> {
+    /// Immutably borrows the wrapped value without checking for a mutable
+    /// borrow only in Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic with the
+    /// location of the conflicting borrow if the value is mutably borrowed.
+    ///
+    /// # Safety
+    /// Calling this method while the value is mutably borrowed is undefined
+    /// behavior when debug assertions are disabled.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn borrow(&self) -> DebugRef<'_, T> {
+        if let Some(state) = self.state.get() {
+            let borrows = state.borrows.get();
+            if checks_enabled() && borrows < 0 {
+                state.conflict("mutably borrowed")
+            }
+            if borrows == 0 {
+                state.location.set(Some(Location::caller()));
+            }
+            state.borrows.set(borrows + 1);
+        }
+        DebugRef { cell: self }
+    }
+
+    /// Mutably borrows the wrapped value without checking for other borrows
+    /// only in Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic with the
+    /// location of the conflicting borrow if the value is already borrowed.
+    ///
+    /// # Safety
+    /// Calling this method while the value is borrowed is undefined behavior
+    /// when debug assertions are disabled.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn borrow_mut(&self) -> DebugRefMut<'_, T> {
+        if let Some(state) = self.state.get() {
+            if checks_enabled() && state.borrows.get() != 0 {
+                state.conflict("borrowed")
+            }
+            state.location.set(Some(Location::caller()));
+            state.borrows.set(-1);
+        }
+        DebugRefMut { cell: self }
+    }
+
+    /// Returns a mutable reference to the wrapped value
+    ///
+    /// This is safe as the `&mut self` guarantees that there are no borrows.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+
+    /// Returns a raw pointer to the wrapped value
+    #[inline]
+    pub const fn as_ptr(&self) -> *mut T {
+        self.value.get()
+    }
+}
+
+impl<T: Default> Default for DebugCell<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl BorrowState {
+    /// Fails because of a conflict with the borrows which are alive
+    #[cold]
+    #[track_caller]
+    fn conflict(&self, borrowed: &str) -> ! {
+        match self.location.get() {
+            Some(location) => fail(
+                FailureKind::Precondition,
+                format_args!("already {} at {}", borrowed, location),
+            ),
+            None => fail(
+                FailureKind::Precondition,
+                format_args!("already {}", borrowed),
+            ),
+        }
+    }
+}
+
+/// A shared borrow of the value in a `DebugCell`
+pub struct DebugRef<'a, T: ?Sized> {
+    cell: &'a DebugCell<T>,
+}
+
+impl<T: ?Sized> Deref for DebugRef<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        // SAFETY: The caller of `borrow()` guaranteed there is no mutable borrow
+        unsafe { &*self.cell.value.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for DebugRef<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(state) = self.cell.state.get() {
+            let borrows = state.borrows.get() - 1;
+            if borrows == 0 {
+                state.location.set(None);
+            }
+            state.borrows.set(borrows);
+        }
+    }
+}
+
+/// A mutable borrow of the value in a `DebugCell`
+pub struct DebugRefMut<'a, T: ?Sized> {
+    cell: &'a DebugCell<T>,
+}
+
+impl<T: ?Sized> Deref for DebugRefMut<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        // SAFETY: The caller of `borrow_mut()` guaranteed exclusive access
+        unsafe { &*self.cell.value.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for DebugRefMut<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: The caller of `borrow_mut()` guaranteed exclusive access
+        unsafe { &mut *self.cell.value.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for DebugRefMut<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(state) = self.cell.state.get() {
+            state.location.set(None);
+            state.borrows.set(0);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Debug only checked conversions to `char`
+
+use crate::failure::{fail, FailureKind};
+use crate::mode::checks_enabled;
+
+/// Converts a `u32` to a `char` without checking that it is a valid Unicode
+/// scalar value only in Release mode.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic with the code
+/// point if `i` is a surrogate or greater than `char::MAX`.
+///
+/// # Safety
+/// Passing a surrogate or a value greater than `char::MAX` is undefined
+/// behavior when debug assertions are disabled.
+#[inline]
+#[track_caller]
+pub unsafe fn debug_char_from_u32_unchecked(i: u32) -> char {
+    if checks_enabled() {
+        match char::from_u32(i) {
+            Some(c) => c,
+            None if i > char::MAX as u32 => fail(
+                FailureKind::Precondition,
+                format_args!("invalid char: {:#x} is greater than char::MAX", i),
+            ),
+            None => fail(
+                FailureKind::Precondition,
+                format_args!("invalid char: {:#x} is a surrogate code point", i),
+            ),
+        }
+    } else {
+        char::from_u32_unchecked(i)
+    }
+}
+
+/// Converts a digit in the given radix to a `char` without checking that it
+/// is valid only in Release mode.
+///
+/// Digits above 9 are converted to lowercase letters, as with
+/// `char::from_digit()`.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic if `radix` is
+/// not between 2 and 36 or `num` is not less than `radix`.
+///
+/// # Safety
+/// Passing an invalid radix or a digit which is not less than `radix` is
+/// undefined behavior when debug assertions are disabled.
+#[inline]
+#[track_caller]
+pub unsafe fn debug_char_from_digit_unchecked(num: u32, radix: u32) -> char {
+    if checks_enabled() {
+        if !(2..=36).contains(&radix) {
+            fail(
+                FailureKind::Precondition,
+                format_args!("invalid radix {}: must be between 2 and 36", radix),
+            )
+        }
+        match char::from_digit(num, radix) {
+            Some(c) => c,
+            None => fail(
+                FailureKind::Precondition,
+                format_args!("invalid digit {} for radix {}", num, radix),
+            ),
+        }
+    } else {
+        // `from_digit` panics on an invalid radix instead of returning
+        // `None`, so that check is only optimized out by assuming it
+        core::hint::assert_unchecked((2..=36).contains(&radix) && num < radix);
+        char::from_digit(num, radix).unwrap_unchecked()
+    }
+}
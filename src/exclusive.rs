@@ -0,0 +1,154 @@
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use core::panic::Location;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::failure::{fail, FailureKind};
+use crate::mode::{checks_enabled, CheckState};
+
+/// Shared mutable state which is only ever accessed by one thread at a time,
+/// checked only in Debug mode.
+///
+/// This is meant for designs which guarantee a single writer without a lock.
+/// When debug assertions are enabled an atomic flag detects two threads
+/// inside the critical section at once. In Release this is an `UnsafeCell<T>`
+/// with the same size and cost, unless the `runtime-switch` or
+/// `scoped-checks` features require tracking anyway.
+pub struct DebugExclusive<T: ?Sized> {
+    /// Location of the `enter()` which is currently inside, or null
+    holder: CheckState<AtomicPtr<Location<'static>>>,
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: Moving the wrapper moves the value, like `Mutex<T>`
+unsafe impl<T: ?Sized + Send> Send for DebugExclusive<T> {}
+// SAFETY: The caller of `enter()` guaranteed that only one thread accesses
+// the value at a time, which makes sharing it like sending it
+unsafe impl<T: ?Sized + Send> Sync for DebugExclusive<T> {}
+
+impl<T> DebugExclusive<T> {
+    /// Creates a new `DebugExclusive` containing `value`
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self {
+            holder: CheckState::new(AtomicPtr::new(ptr::null_mut())),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Consumes the `DebugExclusive`, returning the wrapped value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: ?Sized> DebugExclusive<T> {
+    /// Enters the critical section without checking for other threads inside
+    /// of it only in Release mode.
+    ///
+    /// The section is left when the returned guard is dropped.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic with the
+    /// location of the other `enter()` if the section was already entered and
+    /// not yet left, whether by another thread or the current one.
+    ///
+    /// # Safety
+    /// Entering the section before the previous guard was dropped is undefined
+    /// behavior when debug assertions are disabled.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn enter(&self) -> DebugExclusiveGuard<'_, T> {
+        if let Some(holder) = self.holder.get() {
+            let location = Location::caller() as *const Location<'static>;
+            if let Err(other) = holder.compare_exchange(
+                ptr::null_mut(),
+                location.cast_mut(),
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                if checks_enabled() {
+                    // SAFETY: Only `&'static Location`s are stored in `holder`
+                    let other = &*other;
+                    fail(
+                        FailureKind::Precondition,
+                        format_args!("critical section was already entered at {}", other),
+                    )
+                }
+            }
+        }
+        DebugExclusiveGuard {
+            exclusive: self,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a mutable reference to the wrapped value
+    ///
+    /// This is safe as the `&mut self` guarantees that no guard is alive.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+
+    /// Returns a raw pointer to the wrapped value
+    #[inline]
+    pub const fn as_ptr(&self) -> *mut T {
+        self.value.get()
+    }
+}
+
+impl<T: Default> Default for DebugExclusive<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+/// Exclusive access to the value in a `DebugExclusive`, leaving the critical
+/// section when dropped
+///
+/// Like `MutexGuard<T>` the guard is only `Sync` if `T` is:
+///
+/// ```compile_fail
+/// fn assert_sync<T: Sync>() {}
+/// assert_sync::<debug_unwraps::DebugExclusiveGuard<'static, core::cell::Cell<u32>>>();
+/// ```
+pub struct DebugExclusiveGuard<'a, T: ?Sized> {
+    exclusive: &'a DebugExclusive<T>,
+    /// The guard hands out `&mut T`, so it must not be `Sync` just because
+    /// `DebugExclusive<T>` is
+    _marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY: Sharing the guard only gives out `&T`, like `MutexGuard<T>`
+unsafe impl<T: ?Sized + Sync> Sync for DebugExclusiveGuard<'_, T> {}
+
+impl<T: ?Sized> Deref for DebugExclusiveGuard<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        // SAFETY: The caller of `enter()` guaranteed exclusive access
+        unsafe { &*self.exclusive.value.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for DebugExclusiveGuard<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: The caller of `enter()` guaranteed exclusive access
+        unsafe { &mut *self.exclusive.value.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for DebugExclusiveGuard<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(holder) = self.exclusive.holder.get() {
+            holder.store(ptr::null_mut(), Ordering::Release);
+        }
+    }
+}
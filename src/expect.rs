@@ -0,0 +1,131 @@
+use core::fmt;
+
+use crate::failure::{fail, FailureKind};
+
+/// Access to `Option` and `Result` for the expansion of `debug_expect!()` and
+/// `debug_unwrap!()`
+pub trait Expect: Sized {
+    /// Expected type after performing an unwrap
+    type Value;
+
+    /// Returns whether the value is `Some()` or `Ok()`
+    fn is_valid(&self) -> bool;
+
+    /// Reports the value as a failure with the provided `message`
+    #[track_caller]
+    fn fail(self, message: fmt::Arguments<'_>) -> !;
+
+    /// Reports the value as a failure of unwrapping the expression `expr`
+    #[track_caller]
+    fn fail_unwrap(self, expr: &'static str) -> !;
+
+    /// Returns the contained `Some()` or `Ok()` variant without checking
+    ///
+    /// # Safety
+    /// The value must be `Some()` or `Ok()`.
+    unsafe fn unwrap_unchecked(self) -> Self::Value;
+}
+
+/// Access to `Result` for the expansion of `debug_expect_err!()`
+pub trait ExpectErr: Sized {
+    /// Expected error type after unwrap
+    type ErrorType;
+
+    /// Returns whether the value is `Err()`
+    fn is_valid(&self) -> bool;
+
+    /// Reports the value as a failure with the provided `message`
+    #[track_caller]
+    fn fail(self, message: fmt::Arguments<'_>) -> !;
+
+    /// Returns the contained `Err()` variant without checking
+    ///
+    /// # Safety
+    /// The value must be `Err()`.
+    unsafe fn unwrap_err_unchecked(self) -> Self::ErrorType;
+}
+
+impl<T> Expect for Option<T> {
+    type Value = T;
+
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.is_some()
+    }
+
+    #[track_caller]
+    fn fail(self, message: fmt::Arguments<'_>) -> ! {
+        fail(FailureKind::None, message)
+    }
+
+    #[track_caller]
+    fn fail_unwrap(self, expr: &'static str) -> ! {
+        fail(FailureKind::None, format_args!("`{}` was None", expr))
+    }
+
+    #[inline]
+    unsafe fn unwrap_unchecked(self) -> Self::Value {
+        self.unwrap_unchecked()
+    }
+}
+
+impl<T, E> Expect for Result<T, E>
+where
+    E: fmt::Debug,
+{
+    type Value = T;
+
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.is_ok()
+    }
+
+    #[track_caller]
+    fn fail(self, message: fmt::Arguments<'_>) -> ! {
+        match self {
+            Ok(_) => unreachable!(),
+            Err(err) => fail(FailureKind::Err, format_args!("{}: {:?}", message, err)),
+        }
+    }
+
+    #[track_caller]
+    fn fail_unwrap(self, expr: &'static str) -> ! {
+        match self {
+            Ok(_) => unreachable!(),
+            Err(err) => fail(
+                FailureKind::Err,
+                format_args!("`{}` was Err: {:?}", expr, err),
+            ),
+        }
+    }
+
+    #[inline]
+    unsafe fn unwrap_unchecked(self) -> Self::Value {
+        self.unwrap_unchecked()
+    }
+}
+
+impl<T, E> ExpectErr for Result<T, E>
+where
+    T: fmt::Debug,
+{
+    type ErrorType = E;
+
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.is_err()
+    }
+
+    #[track_caller]
+    fn fail(self, message: fmt::Arguments<'_>) -> ! {
+        match self {
+            Ok(value) => fail(FailureKind::Ok, format_args!("{}: {:?}", message, value)),
+            Err(_) => unreachable!(),
+        }
+    }
+
+    #[inline]
+    unsafe fn unwrap_err_unchecked(self) -> Self::ErrorType {
+        self.unwrap_err_unchecked()
+    }
+}
@@ -0,0 +1,116 @@
+use core::fmt;
+use core::mem;
+use core::panic::Location;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// Kind of check which failed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FailureKind {
+    /// Expected `Some()` but the `Option` was `None`
+    None,
+    /// Expected `Ok()` but the `Result` was `Err()`
+    Err,
+    /// Expected `Err()` but the `Result` was `Ok()`
+    Ok,
+    /// Reached a `debug_unreachable!()`
+    Unreachable,
+    /// The condition of a `debug_assume!()` was `false`
+    Assumption,
+    /// Violated the precondition of an unchecked operation, such as an index
+    /// being in bounds
+    Precondition,
+}
+
+/// Description of a failed check passed to the `FailureHandler`
+#[derive(Clone, Copy, Debug)]
+pub struct Failure<'a> {
+    kind: FailureKind,
+    message: fmt::Arguments<'a>,
+    location: &'static Location<'static>,
+}
+
+impl<'a> Failure<'a> {
+    /// Returns the kind of check which failed
+    pub fn kind(&self) -> FailureKind {
+        self.kind
+    }
+
+    /// Returns the message describing the failure
+    pub fn message(&self) -> fmt::Arguments<'a> {
+        self.message
+    }
+
+    /// Returns the location of the failed check in the calling code
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+}
+
+impl fmt::Display for Failure<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}", self.message, self.location)
+    }
+}
+
+/// Function called whenever a check fails
+///
+/// If the handler returns the failure is raised as a panic at the location of
+/// the failed check, as there is no valid value to continue with. Only the
+/// `debug_unwrap_or()` family continues with its fallback value instead.
+pub type FailureHandler = fn(&Failure<'_>);
+
+/// Registered `FailureHandler`, or null if none was registered
+static HANDLER: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Registers the process-wide handler called whenever a check fails.
+///
+/// Without a handler failures panic. A handler can abort or panic with its own
+/// payload. It can also log or record failures and return, but then only the
+/// methods with a fallback value, such as `debug_unwrap_or()`, continue. Every
+/// other check still panics afterwards, as continuing would be undefined
+/// behavior.
+pub fn set_failure_handler(handler: FailureHandler) {
+    HANDLER.store(handler as *mut (), Ordering::Release);
+}
+
+/// Reports a failed check to the registered handler and then panics
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub fn fail(kind: FailureKind, message: fmt::Arguments<'_>) -> ! {
+    call_handler(kind, message);
+    panic!("{}", message)
+}
+
+/// Reports a failed check which has a fallback value to continue with.
+///
+/// Unlike `fail()` this only panics if no handler is registered, so that a
+/// handler which returns lets the caller use the fallback.
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn report(kind: FailureKind, message: fmt::Arguments<'_>) {
+    if !call_handler(kind, message) {
+        panic!("{}", message)
+    }
+}
+
+/// Calls the registered handler, returning whether there was one
+#[inline]
+#[track_caller]
+fn call_handler(kind: FailureKind, message: fmt::Arguments<'_>) -> bool {
+    let handler = HANDLER.load(Ordering::Acquire);
+    if handler.is_null() {
+        return false;
+    }
+    // SAFETY: Only `FailureHandler`s are ever stored in `HANDLER`
+    let handler = unsafe { mem::transmute::<*mut (), FailureHandler>(handler) };
+    handler(&Failure {
+        kind,
+        message,
+        location: Location::caller(),
+    });
+    true
+}
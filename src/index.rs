@@ -0,0 +1,132 @@
+use core::slice::SliceIndex;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::failure::{fail, FailureKind};
+use crate::mode::checks_enabled;
+
+/// Extension trait providing debug only bounds checking of slice indexing
+///
+/// Like `slice::get_unchecked` both single indices and ranges are accepted.
+pub trait DebugIndexExt {
+    /// Type of the elements being indexed
+    type Item;
+
+    /// Returns a reference to an element or subslice without doing bounds
+    /// checking only in Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if the
+    /// index or range is out of bounds.
+    ///
+    /// # Safety
+    /// Calling this method with an out-of-bounds index or range is undefined
+    /// behavior when debug assertions are disabled.
+    unsafe fn debug_get_unchecked<I>(&self, index: I) -> &I::Output
+    where
+        I: SliceIndex<[Self::Item]>;
+
+    /// Returns a mutable reference to an element or subslice without doing
+    /// bounds checking only in Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if the
+    /// index or range is out of bounds.
+    ///
+    /// # Safety
+    /// Calling this method with an out-of-bounds index or range is undefined
+    /// behavior when debug assertions are disabled.
+    unsafe fn debug_get_unchecked_mut<I>(&mut self, index: I) -> &mut I::Output
+    where
+        I: SliceIndex<[Self::Item]>;
+}
+
+impl<T> DebugIndexExt for [T] {
+    type Item = T;
+
+    #[inline]
+    #[track_caller]
+    unsafe fn debug_get_unchecked<I>(&self, index: I) -> &I::Output
+    where
+        I: SliceIndex<[Self::Item]>,
+    {
+        if checks_enabled() {
+            let len = self.len();
+            match self.get(index) {
+                Some(value) => value,
+                None => fail(
+                    FailureKind::Precondition,
+                    format_args!("index out of bounds for slice of length {}", len),
+                ),
+            }
+        } else {
+            self.get_unchecked(index)
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn debug_get_unchecked_mut<I>(&mut self, index: I) -> &mut I::Output
+    where
+        I: SliceIndex<[Self::Item]>,
+    {
+        if checks_enabled() {
+            let len = self.len();
+            match self.get_mut(index) {
+                Some(value) => value,
+                None => fail(
+                    FailureKind::Precondition,
+                    format_args!("index out of bounds for slice of length {}", len),
+                ),
+            }
+        } else {
+            self.get_unchecked_mut(index)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> DebugIndexExt for Vec<T> {
+    type Item = T;
+
+    #[inline]
+    #[track_caller]
+    unsafe fn debug_get_unchecked<I>(&self, index: I) -> &I::Output
+    where
+        I: SliceIndex<[Self::Item]>,
+    {
+        self.as_slice().debug_get_unchecked(index)
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn debug_get_unchecked_mut<I>(&mut self, index: I) -> &mut I::Output
+    where
+        I: SliceIndex<[Self::Item]>,
+    {
+        self.as_mut_slice().debug_get_unchecked_mut(index)
+    }
+}
+
+impl<T, const N: usize> DebugIndexExt for [T; N] {
+    type Item = T;
+
+    #[inline]
+    #[track_caller]
+    unsafe fn debug_get_unchecked<I>(&self, index: I) -> &I::Output
+    where
+        I: SliceIndex<[Self::Item]>,
+    {
+        self.as_slice().debug_get_unchecked(index)
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn debug_get_unchecked_mut<I>(&mut self, index: I) -> &mut I::Output
+    where
+        I: SliceIndex<[Self::Item]>,
+    {
+        self.as_mut_slice().debug_get_unchecked_mut(index)
+    }
+}
@@ -1,7 +1,67 @@
 #![doc = include_str!("../README.md")]
 #![deny(missing_docs)]
+#![no_std]
 
-use std::fmt;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::fmt;
+
+mod macros;
+
+mod arith;
+mod cell;
+#[cfg(target_has_atomic = "ptr")]
+mod exclusive;
+mod expect;
+mod failure;
+mod index;
+mod maybe_uninit;
+mod mode;
+#[cfg(feature = "runtime-switch")]
+mod runtime;
+#[cfg(feature = "scoped-checks")]
+mod scoped;
+#[cfg(feature = "std")]
+mod single_thread;
+
+pub mod char;
+pub mod nonzero;
+pub mod ptr;
+pub mod slice;
+pub mod utf8;
+
+pub use arith::DebugArithExt;
+pub use cell::{DebugCell, DebugRef, DebugRefMut};
+#[cfg(feature = "derive")]
+pub use debug_unwraps_derive::DebugFromRepr;
+#[cfg(target_has_atomic = "ptr")]
+pub use exclusive::{DebugExclusive, DebugExclusiveGuard};
+pub use failure::{set_failure_handler, Failure, FailureHandler, FailureKind};
+pub use index::DebugIndexExt;
+pub use maybe_uninit::DebugMaybeUninit;
+#[cfg(feature = "runtime-switch")]
+pub use runtime::{mode, set_mode, Mode};
+#[cfg(feature = "scoped-checks")]
+pub use scoped::{with_checks, without_checks};
+#[cfg(feature = "std")]
+pub use single_thread::DebugSingleThread;
+
+use failure::{fail, report};
+use mode::checks_enabled;
+
+/// Support items for the exported macros, not part of the public API
+#[doc(hidden)]
+pub mod __private {
+    // Macros must use this instead of `cfg!(debug_assertions)` so that the
+    // decision is made by this crate rather than the crate expanding them
+    pub use crate::mode::checks_enabled;
+
+    pub use crate::expect::{Expect, ExpectErr};
+    pub use crate::failure::fail;
+}
 
 /// Extension trait providing debug only checking of item validity
 pub trait DebugUnwrapExt {
@@ -31,6 +91,52 @@ pub trait DebugUnwrapExt {
     /// Calling this method on `None` or `Err()` is undefined behavior when
     /// debug assertions are disabled.
     unsafe fn debug_expect_unchecked(self, msg: &str) -> Self::Value;
+
+    /// Returns the contained `Some()` or `Ok()` variant without checking
+    /// the discriminant only in Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic with the
+    /// message returned by `f`, which is only called on failure.
+    ///
+    /// # Safety
+    /// Calling this method on `None` or `Err()` is undefined behavior when
+    /// debug assertions are disabled.
+    unsafe fn debug_expect_unchecked_with<F, M>(self, f: F) -> Self::Value
+    where
+        F: FnOnce() -> M,
+        M: fmt::Display;
+
+    /// Returns the contained `Some()` or `Ok()` variant, falling back to
+    /// `default` only in Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if the
+    /// value is not `Some()` or `Ok()`, unless a registered `FailureHandler`
+    /// returns, in which case the fallback is used.
+    fn debug_unwrap_or(self, default: Self::Value) -> Self::Value;
+
+    /// Returns the contained `Some()` or `Ok()` variant, falling back to
+    /// `Default::default()` only in Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if the
+    /// value is not `Some()` or `Ok()`, unless a registered `FailureHandler`
+    /// returns, in which case the fallback is used.
+    fn debug_unwrap_or_default(self) -> Self::Value
+    where
+        Self::Value: Default;
+
+    /// Returns the contained `Some()` or `Ok()` variant, falling back to
+    /// the result of `f` only in Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if the
+    /// value is not `Some()` or `Ok()`, unless a registered `FailureHandler`
+    /// returns, in which case the fallback is used.
+    fn debug_unwrap_or_else<F>(self, f: F) -> Self::Value
+    where
+        F: FnOnce() -> Self::Value;
 }
 
 /// Extension trait providing debug only checking of error validity
@@ -61,6 +167,22 @@ pub trait DebugUnwrapErrExt {
     /// Calling this method on `None` or `Err()` is undefined behavior when
     /// debug assertions are disabled.
     unsafe fn debug_expect_err_unchecked(self, msg: &str) -> Self::ErrorType;
+
+    /// Returns the contained `Err()` variant without checking
+    /// the discriminant only in Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if the
+    /// Result is not `Result::Err()` and will print the message returned by
+    /// `f`, which is only called on failure.
+    ///
+    /// # Safety
+    /// Calling this method on `Ok()` is undefined behavior when debug
+    /// assertions are disabled.
+    unsafe fn debug_expect_err_unchecked_with<F, M>(self, f: F) -> Self::ErrorType
+    where
+        F: FnOnce() -> M,
+        M: fmt::Display;
 }
 
 impl<T> DebugUnwrapExt for Option<T> {
@@ -69,29 +191,83 @@ impl<T> DebugUnwrapExt for Option<T> {
     #[inline]
     #[track_caller]
     unsafe fn debug_unwrap_unchecked(self) -> Self::Value {
-        #[cfg(debug_assertions)]
-        {
-            self.unwrap()
-        }
-        #[cfg(not(debug_assertions))]
-        {
+        if checks_enabled() {
+            match self {
+                Some(value) => value,
+                None => fail(
+                    FailureKind::None,
+                    format_args!("called `Option::unwrap()` on a `None` value"),
+                ),
+            }
+        } else {
             self.unwrap_unchecked()
         }
     }
 
     #[inline]
     #[track_caller]
-    #[cfg_attr(not(debug_assertions), allow(unused_variables))]
     unsafe fn debug_expect_unchecked(self, msg: &str) -> Self::Value {
-        #[cfg(debug_assertions)]
-        {
-            self.expect(msg)
+        if checks_enabled() {
+            match self {
+                Some(value) => value,
+                None => fail(FailureKind::None, format_args!("{}", msg)),
+            }
+        } else {
+            self.unwrap_unchecked()
         }
-        #[cfg(not(debug_assertions))]
-        {
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn debug_expect_unchecked_with<F, M>(self, f: F) -> Self::Value
+    where
+        F: FnOnce() -> M,
+        M: fmt::Display,
+    {
+        if checks_enabled() {
+            match self {
+                Some(value) => value,
+                None => fail(FailureKind::None, format_args!("{}", f())),
+            }
+        } else {
             self.unwrap_unchecked()
         }
     }
+
+    #[inline]
+    #[track_caller]
+    fn debug_unwrap_or(self, default: Self::Value) -> Self::Value {
+        self.debug_unwrap_or_else(|| default)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn debug_unwrap_or_default(self) -> Self::Value
+    where
+        Self::Value: Default,
+    {
+        self.debug_unwrap_or_else(Default::default)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn debug_unwrap_or_else<F>(self, f: F) -> Self::Value
+    where
+        F: FnOnce() -> Self::Value,
+    {
+        match self {
+            Some(value) => value,
+            None => {
+                if checks_enabled() {
+                    report(
+                        FailureKind::None,
+                        format_args!("called `Option::unwrap()` on a `None` value"),
+                    );
+                }
+                f()
+            }
+        }
+    }
 }
 
 impl<T, E> DebugUnwrapExt for Result<T, E>
@@ -103,29 +279,83 @@ where
     #[inline]
     #[track_caller]
     unsafe fn debug_unwrap_unchecked(self) -> Self::Value {
-        #[cfg(debug_assertions)]
-        {
-            self.unwrap()
-        }
-        #[cfg(not(debug_assertions))]
-        {
+        if checks_enabled() {
+            match self {
+                Ok(value) => value,
+                Err(err) => fail(
+                    FailureKind::Err,
+                    format_args!("called `Result::unwrap()` on an `Err` value: {:?}", err),
+                ),
+            }
+        } else {
             self.unwrap_unchecked()
         }
     }
 
     #[inline]
     #[track_caller]
-    #[cfg_attr(not(debug_assertions), allow(unused_variables))]
     unsafe fn debug_expect_unchecked(self, msg: &str) -> Self::Value {
-        #[cfg(debug_assertions)]
-        {
-            self.expect(msg)
+        if checks_enabled() {
+            match self {
+                Ok(value) => value,
+                Err(err) => fail(FailureKind::Err, format_args!("{}: {:?}", msg, err)),
+            }
+        } else {
+            self.unwrap_unchecked()
         }
-        #[cfg(not(debug_assertions))]
-        {
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn debug_expect_unchecked_with<F, M>(self, f: F) -> Self::Value
+    where
+        F: FnOnce() -> M,
+        M: fmt::Display,
+    {
+        if checks_enabled() {
+            match self {
+                Ok(value) => value,
+                Err(err) => fail(FailureKind::Err, format_args!("{}: {:?}", f(), err)),
+            }
+        } else {
             self.unwrap_unchecked()
         }
     }
+
+    #[inline]
+    #[track_caller]
+    fn debug_unwrap_or(self, default: Self::Value) -> Self::Value {
+        self.debug_unwrap_or_else(|| default)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn debug_unwrap_or_default(self) -> Self::Value
+    where
+        Self::Value: Default,
+    {
+        self.debug_unwrap_or_else(Default::default)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn debug_unwrap_or_else<F>(self, f: F) -> Self::Value
+    where
+        F: FnOnce() -> Self::Value,
+    {
+        match self {
+            Ok(value) => value,
+            Err(err) => {
+                if checks_enabled() {
+                    report(
+                        FailureKind::Err,
+                        format_args!("called `Result::unwrap()` on an `Err` value: {:?}", err),
+                    );
+                }
+                f()
+            }
+        }
+    }
 }
 
 impl<T, E> DebugUnwrapErrExt for Result<T, E>
@@ -137,26 +367,48 @@ where
     #[inline]
     #[track_caller]
     unsafe fn debug_unwrap_err_unchecked(self) -> Self::ErrorType {
-        #[cfg(debug_assertions)]
-        {
-            self.unwrap_err()
-        }
-        #[cfg(not(debug_assertions))]
-        {
+        if checks_enabled() {
+            match self {
+                Ok(value) => fail(
+                    FailureKind::Ok,
+                    format_args!(
+                        "called `Result::unwrap_err()` on an `Ok` value: {:?}",
+                        value
+                    ),
+                ),
+                Err(err) => err,
+            }
+        } else {
             self.unwrap_err_unchecked()
         }
     }
 
     #[inline]
     #[track_caller]
-    #[cfg_attr(not(debug_assertions), allow(unused_variables))]
     unsafe fn debug_expect_err_unchecked(self, msg: &str) -> Self::ErrorType {
-        #[cfg(debug_assertions)]
-        {
-            self.expect_err(msg)
+        if checks_enabled() {
+            match self {
+                Ok(value) => fail(FailureKind::Ok, format_args!("{}: {:?}", msg, value)),
+                Err(err) => err,
+            }
+        } else {
+            self.unwrap_err_unchecked()
         }
-        #[cfg(not(debug_assertions))]
-        {
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn debug_expect_err_unchecked_with<F, M>(self, f: F) -> Self::ErrorType
+    where
+        F: FnOnce() -> M,
+        M: fmt::Display,
+    {
+        if checks_enabled() {
+            match self {
+                Ok(value) => fail(FailureKind::Ok, format_args!("{}: {:?}", f(), value)),
+                Err(err) => err,
+            }
+        } else {
             self.unwrap_err_unchecked()
         }
     }
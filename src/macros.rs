@@ -0,0 +1,173 @@
+/// Marks a branch as unreachable, checking it only in Debug mode.
+///
+/// Accepts the same arguments as `unreachable!()`.
+///
+/// # Panics
+/// When debug assertions are enabled this macro will always panic with the
+/// provided message.
+///
+/// # Safety
+/// Reaching this macro is undefined behavior when debug assertions are
+/// disabled. It expands to a call to `core::hint::unreachable_unchecked()`
+/// and so must be used inside of an `unsafe` block.
+#[macro_export]
+macro_rules! debug_unreachable {
+    () => {
+        if $crate::__private::checks_enabled() {
+            $crate::__private::fail(
+                $crate::FailureKind::Unreachable,
+                ::core::format_args!("internal error: entered unreachable code"),
+            )
+        } else {
+            ::core::hint::unreachable_unchecked()
+        }
+    };
+    ($($arg:tt)+) => {
+        if $crate::__private::checks_enabled() {
+            $crate::__private::fail(
+                $crate::FailureKind::Unreachable,
+                ::core::format_args!(
+                    "internal error: entered unreachable code: {}",
+                    ::core::format_args!($($arg)+)
+                ),
+            )
+        } else {
+            ::core::hint::unreachable_unchecked()
+        }
+    };
+}
+
+/// Asserts that a condition holds, checking it only in Debug mode.
+///
+/// Accepts the same arguments as `assert!()`. When debug assertions are
+/// disabled the condition is instead passed to
+/// `core::hint::assert_unchecked()` so the optimizer may rely on it.
+///
+/// # Panics
+/// When debug assertions are enabled this macro will panic if the condition
+/// evaluates to `false`.
+///
+/// # Safety
+/// The condition evaluating to `false` is undefined behavior when debug
+/// assertions are disabled. It expands to a call to
+/// `core::hint::assert_unchecked()` and so must be used inside of an `unsafe`
+/// block.
+#[macro_export]
+macro_rules! debug_assume {
+    ($cond:expr $(,)?) => {
+        if $crate::__private::checks_enabled() {
+            if !$cond {
+                $crate::__private::fail(
+                    $crate::FailureKind::Assumption,
+                    ::core::format_args!("assumption failed: {}", ::core::stringify!($cond)),
+                )
+            }
+        } else {
+            ::core::hint::assert_unchecked($cond)
+        }
+    };
+    ($cond:expr, $($arg:tt)+) => {
+        if $crate::__private::checks_enabled() {
+            if !$cond {
+                $crate::__private::fail(
+                    $crate::FailureKind::Assumption,
+                    ::core::format_args!($($arg)+),
+                )
+            }
+        } else {
+            ::core::hint::assert_unchecked($cond)
+        }
+    };
+}
+
+/// Returns the contained `Some()` or `Ok()` variant without checking the
+/// discriminant only in Release mode.
+///
+/// The message accepts the same arguments as `format!()`, which are only
+/// evaluated when the check fails.
+///
+/// # Panics
+/// When debug assertions are enabled this macro will panic with the formatted
+/// message if the value is not `Some()` or `Ok()`.
+///
+/// # Safety
+/// Using this macro on `None` or `Err()` is undefined behavior when debug
+/// assertions are disabled. It expands to a call to an `unsafe` function and
+/// so must be used inside of an `unsafe` block.
+#[macro_export]
+macro_rules! debug_expect {
+    ($value:expr, $($arg:tt)+) => {
+        // Like `assert_eq!()`, `match` keeps temporaries alive for the whole
+        // statement instead of dropping them at the end of a `let`
+        match $value {
+            value => {
+                if $crate::__private::checks_enabled() && !$crate::__private::Expect::is_valid(&value) {
+                    $crate::__private::Expect::fail(value, ::core::format_args!($($arg)+))
+                }
+                $crate::__private::Expect::unwrap_unchecked(value)
+            }
+        }
+    };
+}
+
+/// Returns the contained `Err()` variant without checking the discriminant
+/// only in Release mode.
+///
+/// The message accepts the same arguments as `format!()`, which are only
+/// evaluated when the check fails.
+///
+/// # Panics
+/// When debug assertions are enabled this macro will panic with the formatted
+/// message if the value is not `Err()`.
+///
+/// # Safety
+/// Using this macro on `Ok()` is undefined behavior when debug assertions are
+/// disabled. It expands to a call to an `unsafe` function and so must be used
+/// inside of an `unsafe` block.
+#[macro_export]
+macro_rules! debug_expect_err {
+    ($value:expr, $($arg:tt)+) => {
+        // Like `assert_eq!()`, `match` keeps temporaries alive for the whole
+        // statement instead of dropping them at the end of a `let`
+        match $value {
+            value => {
+                if $crate::__private::checks_enabled() && !$crate::__private::ExpectErr::is_valid(&value) {
+                    $crate::__private::ExpectErr::fail(value, ::core::format_args!($($arg)+))
+                }
+                $crate::__private::ExpectErr::unwrap_err_unchecked(value)
+            }
+        }
+    };
+}
+
+/// Returns the contained `Some()` or `Ok()` variant without checking the
+/// discriminant only in Release mode.
+///
+/// Unlike `debug_expect!()` no message is needed, as the failure is described
+/// using the unwrapped expression itself.
+///
+/// # Panics
+/// When debug assertions are enabled this macro will panic with a message
+/// naming the expression if the value is not `Some()` or `Ok()`.
+///
+/// # Safety
+/// Using this macro on `None` or `Err()` is undefined behavior when debug
+/// assertions are disabled. It expands to a call to an `unsafe` function and
+/// so must be used inside of an `unsafe` block.
+#[macro_export]
+macro_rules! debug_unwrap {
+    ($value:expr $(,)?) => {
+        // Like `assert_eq!()`, `match` keeps temporaries alive for the whole
+        // statement instead of dropping them at the end of a `let`
+        match $value {
+            value => {
+                if $crate::__private::checks_enabled()
+                    && !$crate::__private::Expect::is_valid(&value)
+                {
+                    $crate::__private::Expect::fail_unwrap(value, ::core::stringify!($value))
+                }
+                $crate::__private::Expect::unwrap_unchecked(value)
+            }
+        }
+    };
+}
@@ -0,0 +1,189 @@
+use core::mem::MaybeUninit;
+
+use crate::failure::{fail, FailureKind};
+use crate::mode::{checks_enabled, CheckState};
+
+/// A `MaybeUninit<T>` which tracks whether it is initialized only in Debug
+/// mode.
+///
+/// When debug assertions are disabled this is a `repr(transparent)` wrapper
+/// with the same layout and cost as `MaybeUninit<T>`, unless the
+/// `runtime-switch` or `scoped-checks` features require tracking anyway. Like
+/// `MaybeUninit<T>` the contained value is never dropped automatically.
+#[cfg_attr(not(debug_unwraps_state), repr(transparent))]
+pub struct DebugMaybeUninit<T> {
+    value: MaybeUninit<T>,
+    init: CheckState<bool>,
+}
+
+impl<T> DebugMaybeUninit<T> {
+    /// Creates a new `DebugMaybeUninit<T>` initialized with `val`
+    #[inline]
+    pub const fn new(val: T) -> Self {
+        Self {
+            value: MaybeUninit::new(val),
+            init: CheckState::new(true),
+        }
+    }
+
+    /// Creates a new `DebugMaybeUninit<T>` in an uninitialized state
+    #[inline]
+    pub const fn uninit() -> Self {
+        Self {
+            value: MaybeUninit::uninit(),
+            init: CheckState::new(false),
+        }
+    }
+
+    /// Initializes the value and returns a mutable reference to it.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if the
+    /// value is already initialized, since the old value would be leaked.
+    #[inline]
+    #[track_caller]
+    pub fn write(&mut self, val: T) -> &mut T {
+        if let Some(init) = self.init.get_mut() {
+            if checks_enabled() && *init {
+                fail(
+                    FailureKind::Precondition,
+                    format_args!("called `DebugMaybeUninit::write()` on an initialized value"),
+                )
+            }
+            *init = true;
+        }
+        self.value.write(val)
+    }
+
+    /// Gets a pointer to the contained value.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const T {
+        self.value.as_ptr()
+    }
+
+    /// Gets a mutable pointer to the contained value.
+    ///
+    /// Initializing the value through this pointer is not tracked, so
+    /// `set_init()` must be called afterwards.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.value.as_mut_ptr()
+    }
+
+    /// Marks the value as initialized after writing it through `as_mut_ptr()`.
+    ///
+    /// # Safety
+    /// The value must have been initialized.
+    #[inline]
+    pub unsafe fn set_init(&mut self) {
+        if let Some(init) = self.init.get_mut() {
+            *init = true;
+        }
+    }
+
+    /// Extracts the value without checking that it is initialized only in
+    /// Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if the
+    /// value is not initialized.
+    ///
+    /// # Safety
+    /// Calling this method on an uninitialized value is undefined behavior
+    /// when debug assertions are disabled.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn assume_init(self) -> T {
+        self.check_init("assume_init");
+        self.value.assume_init()
+    }
+
+    /// Reads the value without checking that it is initialized only in
+    /// Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if the
+    /// value is not initialized.
+    ///
+    /// # Safety
+    /// The same requirements as for `MaybeUninit::assume_init_read()` apply.
+    /// Calling this method on an uninitialized value is undefined behavior
+    /// when debug assertions are disabled.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn assume_init_read(&self) -> T {
+        self.check_init("assume_init_read");
+        self.value.assume_init_read()
+    }
+
+    /// Gets a shared reference to the value without checking that it is
+    /// initialized only in Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if the
+    /// value is not initialized.
+    ///
+    /// # Safety
+    /// Calling this method on an uninitialized value is undefined behavior
+    /// when debug assertions are disabled.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn assume_init_ref(&self) -> &T {
+        self.check_init("assume_init_ref");
+        self.value.assume_init_ref()
+    }
+
+    /// Gets a mutable reference to the value without checking that it is
+    /// initialized only in Release mode.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if the
+    /// value is not initialized.
+    ///
+    /// # Safety
+    /// Calling this method on an uninitialized value is undefined behavior
+    /// when debug assertions are disabled.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn assume_init_mut(&mut self) -> &mut T {
+        self.check_init("assume_init_mut");
+        self.value.assume_init_mut()
+    }
+
+    /// Drops the value in place without checking that it is initialized only
+    /// in Release mode, leaving it uninitialized.
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if the
+    /// value is not initialized.
+    ///
+    /// # Safety
+    /// Calling this method on an uninitialized value is undefined behavior
+    /// when debug assertions are disabled.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn assume_init_drop(&mut self) {
+        self.check_init("assume_init_drop");
+        if let Some(init) = self.init.get_mut() {
+            *init = false;
+        }
+        self.value.assume_init_drop()
+    }
+
+    /// Fails if the value is known to be uninitialized
+    #[inline]
+    #[track_caller]
+    fn check_init(&self, method: &str) {
+        if let Some(&init) = self.init.get() {
+            if checks_enabled() && !init {
+                fail(
+                    FailureKind::Precondition,
+                    format_args!(
+                        "called `DebugMaybeUninit::{}()` on an uninitialized value",
+                        method
+                    ),
+                )
+            }
+        }
+    }
+}
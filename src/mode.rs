@@ -0,0 +1,95 @@
+#[cfg(all(feature = "always-check", feature = "never-check"))]
+compile_error!("the `always-check` and `never-check` features are mutually exclusive");
+
+#[cfg(all(debug_unwraps = "check", debug_unwraps = "unchecked"))]
+compile_error!("`--cfg debug_unwraps=\"check\"` and `--cfg debug_unwraps=\"unchecked\"` are mutually exclusive");
+
+/// Whether the debug checks are performed when nothing else overrides it.
+///
+/// The build script sets `debug_unwraps_checks` from `debug_assertions`, the
+/// `always-check` and `never-check` features and the `debug_unwraps` cfg.
+pub(crate) const CHECKS_BY_DEFAULT: bool = cfg!(debug_unwraps_checks);
+
+/// Returns whether the debug checks should be performed.
+#[inline(always)]
+pub fn checks_enabled() -> bool {
+    #[cfg(feature = "scoped-checks")]
+    if let Some(enabled) = crate::scoped::thread_override() {
+        return enabled;
+    }
+
+    #[cfg(feature = "runtime-switch")]
+    {
+        crate::runtime::mode() == crate::runtime::Mode::Check
+    }
+    #[cfg(not(feature = "runtime-switch"))]
+    {
+        CHECKS_BY_DEFAULT
+    }
+}
+
+/// Stores a `T` only when the checks may be performed.
+///
+/// Types which need extra state for their checks keep it in here, so that it
+/// takes up no space when the checks are compiled out. The build script sets
+/// `debug_unwraps_state` if `CHECKS_BY_DEFAULT` holds or a feature choosing
+/// whether to check at runtime is enabled.
+#[cfg(debug_unwraps_state)]
+pub(crate) struct CheckState<T>(T);
+
+#[cfg(debug_unwraps_state)]
+impl<T> CheckState<T> {
+    #[inline(always)]
+    pub(crate) const fn new(state: T) -> Self {
+        CheckState(state)
+    }
+
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    pub(crate) fn new_with<F: FnOnce() -> T>(f: F) -> Self {
+        CheckState(f())
+    }
+
+    #[inline(always)]
+    pub(crate) fn get(&self) -> Option<&T> {
+        Some(&self.0)
+    }
+
+    #[inline(always)]
+    pub(crate) fn get_mut(&mut self) -> Option<&mut T> {
+        Some(&mut self.0)
+    }
+}
+
+/// Stores a `T` only when the checks may be performed.
+///
+/// Without `debug_unwraps_state` this is a zero-sized type with an alignment
+/// of one.
+#[cfg(not(debug_unwraps_state))]
+pub(crate) struct CheckState<T>(core::marker::PhantomData<T>);
+
+#[cfg(not(debug_unwraps_state))]
+impl<T> CheckState<T> {
+    #[inline(always)]
+    pub(crate) const fn new(state: T) -> Self {
+        core::mem::forget(state);
+        CheckState(core::marker::PhantomData)
+    }
+
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    pub(crate) fn new_with<F: FnOnce() -> T>(f: F) -> Self {
+        let _ = f;
+        CheckState(core::marker::PhantomData)
+    }
+
+    #[inline(always)]
+    pub(crate) fn get(&self) -> Option<&T> {
+        None
+    }
+
+    #[inline(always)]
+    pub(crate) fn get_mut(&mut self) -> Option<&mut T> {
+        None
+    }
+}
@@ -0,0 +1,93 @@
+//! Debug only checked construction of the `NonZero` integer types
+
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use crate::failure::{fail, FailureKind};
+use crate::mode::checks_enabled;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A `NonZero` integer type such as `NonZeroU32`
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait NonZeroInteger: Sized + private::Sealed {
+    /// Integer type which may be zero, such as `u32` for `NonZeroU32`
+    type Primitive;
+
+    /// Creates the non-zero integer if `value` is not zero
+    fn new(value: Self::Primitive) -> Option<Self>;
+
+    /// Creates the non-zero integer without checking whether `value` is zero
+    ///
+    /// # Safety
+    /// `value` must not be zero.
+    unsafe fn new_unchecked(value: Self::Primitive) -> Self;
+}
+
+macro_rules! impl_nonzero_integer {
+    ($($t:ty => $p:ty,)*) => {$(
+        impl private::Sealed for $t {}
+
+        impl NonZeroInteger for $t {
+            type Primitive = $p;
+
+            #[inline]
+            fn new(value: Self::Primitive) -> Option<Self> {
+                <$t>::new(value)
+            }
+
+            #[inline]
+            unsafe fn new_unchecked(value: Self::Primitive) -> Self {
+                <$t>::new_unchecked(value)
+            }
+        }
+    )*};
+}
+
+impl_nonzero_integer! {
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroUsize => usize,
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128 => i128,
+    NonZeroIsize => isize,
+}
+
+/// Creates a non-zero integer without checking that `value` is not zero only
+/// in Release mode.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic if `value` is
+/// zero.
+///
+/// # Safety
+/// Passing zero is undefined behavior when debug assertions are disabled.
+#[inline]
+#[track_caller]
+pub unsafe fn debug_new_unchecked<N>(value: N::Primitive) -> N
+where
+    N: NonZeroInteger,
+{
+    if checks_enabled() {
+        match N::new(value) {
+            Some(value) => value,
+            None => fail(
+                FailureKind::Precondition,
+                format_args!("called `debug_new_unchecked()` with zero"),
+            ),
+        }
+    } else {
+        N::new_unchecked(value)
+    }
+}
@@ -0,0 +1,267 @@
+//! Debug only checked operations on raw pointers
+
+use core::mem;
+use core::ops::Range;
+use core::ptr::{self, NonNull};
+
+use crate::failure::{fail, FailureKind};
+use crate::mode::checks_enabled;
+
+/// Creates a `NonNull` without checking that `ptr` is not null only in
+/// Release mode.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic if `ptr` is
+/// null.
+///
+/// # Safety
+/// Passing a null pointer is undefined behavior when debug assertions are
+/// disabled.
+#[inline]
+#[track_caller]
+pub unsafe fn debug_nonnull_unchecked<T>(ptr: *mut T) -> NonNull<T>
+where
+    T: ?Sized,
+{
+    if checks_enabled() {
+        match NonNull::new(ptr) {
+            Some(ptr) => ptr,
+            None => fail(
+                FailureKind::Precondition,
+                format_args!("called `debug_nonnull_unchecked()` with a null pointer"),
+            ),
+        }
+    } else {
+        NonNull::new_unchecked(ptr)
+    }
+}
+
+/// Reads the value from `ptr` without checking that it is non-null and
+/// aligned only in Release mode.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic if `ptr` is
+/// null or not aligned for `T`.
+///
+/// # Safety
+/// The same requirements as for `core::ptr::read()` apply. Additionally
+/// passing a null or misaligned pointer is undefined behavior when debug
+/// assertions are disabled.
+#[inline]
+#[track_caller]
+pub unsafe fn debug_ptr_read_unchecked<T>(ptr: *const T) -> T {
+    if checks_enabled() {
+        check_deref(ptr);
+    }
+    ptr.read()
+}
+
+/// Reads the value from `ptr` without checking that it is non-null, aligned
+/// and inside of `bounds` only in Release mode.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic if `ptr` is
+/// null, not aligned for `T` or the value is not entirely inside of `bounds`.
+///
+/// # Safety
+/// The same requirements as for `core::ptr::read()` apply. Additionally
+/// passing a null, misaligned or out of bounds pointer is undefined behavior
+/// when debug assertions are disabled.
+#[inline]
+#[track_caller]
+pub unsafe fn debug_ptr_read_unchecked_in<T>(ptr: *const T, bounds: Range<*const T>) -> T {
+    if checks_enabled() {
+        check_deref(ptr);
+        check_bounds(ptr, 1, &bounds);
+    }
+    ptr.read()
+}
+
+/// Dereferences `ptr` to a shared reference without checking that it is
+/// non-null and aligned only in Release mode.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic if `ptr` is
+/// null or not aligned for `T`.
+///
+/// # Safety
+/// The same requirements as for `&*ptr` apply, including that `ptr` points
+/// to a valid `T` which is not mutated for `'a`. Additionally passing a null
+/// or misaligned pointer is undefined behavior when debug assertions are
+/// disabled.
+#[inline]
+#[track_caller]
+pub unsafe fn debug_ref_unchecked<'a, T>(ptr: *const T) -> &'a T {
+    if checks_enabled() {
+        check_deref(ptr);
+    }
+    &*ptr
+}
+
+/// Dereferences `ptr` to a shared reference without checking that it is
+/// non-null, aligned and inside of `bounds` only in Release mode.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic if `ptr` is
+/// null, not aligned for `T` or the value is not entirely inside of `bounds`.
+///
+/// # Safety
+/// The same requirements as for `&*ptr` apply, including that `ptr` points
+/// to a valid `T` which is not mutated for `'a`. Additionally passing a null,
+/// misaligned or out of bounds pointer is undefined behavior when debug
+/// assertions are disabled.
+#[inline]
+#[track_caller]
+pub unsafe fn debug_ref_unchecked_in<'a, T>(ptr: *const T, bounds: Range<*const T>) -> &'a T {
+    if checks_enabled() {
+        check_deref(ptr);
+        check_bounds(ptr, 1, &bounds);
+    }
+    &*ptr
+}
+
+/// Dereferences `ptr` to a mutable reference without checking that it is
+/// non-null and aligned only in Release mode.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic if `ptr` is
+/// null or not aligned for `T`.
+///
+/// # Safety
+/// The same requirements as for `&mut *ptr` apply, including that `ptr`
+/// points to a valid `T` which is not otherwise accessed for `'a`.
+/// Additionally passing a null or misaligned pointer is undefined behavior
+/// when debug assertions are disabled.
+#[inline]
+#[track_caller]
+pub unsafe fn debug_mut_unchecked<'a, T>(ptr: *mut T) -> &'a mut T {
+    if checks_enabled() {
+        check_deref(ptr);
+    }
+    &mut *ptr
+}
+
+/// Dereferences `ptr` to a mutable reference without checking that it is
+/// non-null, aligned and inside of `bounds` only in Release mode.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic if `ptr` is
+/// null, not aligned for `T` or the value is not entirely inside of `bounds`.
+///
+/// # Safety
+/// The same requirements as for `&mut *ptr` apply, including that `ptr`
+/// points to a valid `T` which is not otherwise accessed for `'a`.
+/// Additionally passing a null, misaligned or out of bounds pointer is
+/// undefined behavior when debug assertions are disabled.
+#[inline]
+#[track_caller]
+pub unsafe fn debug_mut_unchecked_in<'a, T>(ptr: *mut T, bounds: Range<*mut T>) -> &'a mut T {
+    if checks_enabled() {
+        check_deref(ptr);
+        check_bounds(ptr, 1, &(bounds.start as *const T..bounds.end as *const T));
+    }
+    &mut *ptr
+}
+
+/// Copies `count` values from `src` to `dst` without checking the pointers
+/// and regions only in Release mode.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic if either
+/// pointer is not aligned for `T` or is null while copying a non-zero number
+/// of bytes, if the size of the copy in bytes overflows or if the two regions
+/// overlap.
+///
+/// # Safety
+/// The same requirements as for `core::ptr::copy_nonoverlapping()` apply.
+/// Violating the ones listed under Panics is undefined behavior when debug
+/// assertions are disabled.
+#[inline]
+#[track_caller]
+pub unsafe fn debug_copy_nonoverlapping<T>(src: *const T, dst: *mut T, count: usize) {
+    if checks_enabled() {
+        let size = match mem::size_of::<T>().checked_mul(count) {
+            Some(size) if size <= isize::MAX as usize => size,
+            _ => fail(
+                FailureKind::Precondition,
+                format_args!(
+                    "copy of {} values of {} bytes overflows isize::MAX bytes",
+                    count,
+                    mem::size_of::<T>()
+                ),
+            ),
+        };
+        // Like `copy_nonoverlapping()` itself, null pointers are fine as long
+        // as nothing is copied
+        if size == 0 {
+            check_aligned(src);
+            check_aligned(dst);
+        } else {
+            check_deref(src);
+            check_deref(dst);
+        }
+        let src_start = src as usize;
+        let dst_start = dst as usize;
+        if src_start < dst_start.wrapping_add(size) && dst_start < src_start.wrapping_add(size) {
+            fail(
+                FailureKind::Precondition,
+                format_args!(
+                    "copy of {} bytes from {:p} to {:p} overlaps",
+                    size, src, dst
+                ),
+            )
+        }
+    }
+    ptr::copy_nonoverlapping(src, dst, count)
+}
+
+/// Fails if `ptr` is null or not aligned for `T`
+#[track_caller]
+pub(crate) fn check_deref<T>(ptr: *const T) {
+    if ptr.is_null() {
+        fail(
+            FailureKind::Precondition,
+            format_args!("null pointer dereference"),
+        )
+    }
+    check_aligned(ptr)
+}
+
+/// Fails if `ptr` is not aligned for `T`
+#[track_caller]
+fn check_aligned<T>(ptr: *const T) {
+    if !ptr.is_aligned() {
+        fail(
+            FailureKind::Precondition,
+            format_args!(
+                "misaligned pointer dereference: address must be a multiple of {:#x} but is {:p}",
+                mem::align_of::<T>(),
+                ptr
+            ),
+        )
+    }
+}
+
+/// Fails if the `len` values of `T` starting at `ptr` are not inside of
+/// `bounds`
+#[track_caller]
+pub(crate) fn check_bounds<T>(ptr: *const T, len: usize, bounds: &Range<*const T>) {
+    let start = ptr as usize;
+    let end = mem::size_of::<T>()
+        .checked_mul(len)
+        .and_then(|size| start.checked_add(size));
+    match end {
+        Some(end) if bounds.start as usize <= start && end <= bounds.end as usize => {}
+        Some(end) => fail(
+            FailureKind::Precondition,
+            format_args!(
+                "pointer range {:#x}..{:#x} is outside of the valid range {:p}..{:p}",
+                start, end, bounds.start, bounds.end
+            ),
+        ),
+        None => fail(
+            FailureKind::Precondition,
+            format_args!("{} values at {:p} overflow the address space", len, ptr),
+        ),
+    }
+}
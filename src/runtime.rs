@@ -0,0 +1,80 @@
+use core::sync::atomic::{AtomicU8, Ordering};
+
+use crate::mode::CHECKS_BY_DEFAULT;
+
+/// Process-wide checking mode used with the `runtime-switch` feature
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// Check the validity of values before taking the unchecked path
+    Check,
+    /// Take the unchecked path without checking the validity of values
+    Unchecked,
+}
+
+#[cfg(feature = "std")]
+const UNINIT: u8 = 0;
+const CHECK: u8 = 1;
+const UNCHECKED: u8 = 2;
+
+const DEFAULT: u8 = if CHECKS_BY_DEFAULT { CHECK } else { UNCHECKED };
+
+/// Current `Mode`, or `UNINIT` until it is read from the environment
+#[cfg(feature = "std")]
+static MODE: AtomicU8 = AtomicU8::new(UNINIT);
+/// Current `Mode`, starting from the build time default without `std`
+#[cfg(not(feature = "std"))]
+static MODE: AtomicU8 = AtomicU8::new(DEFAULT);
+
+/// Sets the process-wide checking mode.
+///
+/// This takes precedence over the `DEBUG_UNWRAPS` environment variable and
+/// applies to every thread from the next check onwards.
+pub fn set_mode(mode: Mode) {
+    let value = match mode {
+        Mode::Check => CHECK,
+        Mode::Unchecked => UNCHECKED,
+    };
+    MODE.store(value, Ordering::Relaxed);
+}
+
+/// Returns the process-wide checking mode.
+///
+/// Unless `set_mode()` was called first, the mode is initialized from the
+/// `DEBUG_UNWRAPS` environment variable, which may be `check` or `unchecked`.
+/// When it is unset or has any other value the build time default is used.
+/// The environment is only read with the `std` feature.
+#[inline]
+pub fn mode() -> Mode {
+    match MODE.load(Ordering::Relaxed) {
+        CHECK => Mode::Check,
+        UNCHECKED => Mode::Unchecked,
+        _ => init_mode(),
+    }
+}
+
+#[cfg(feature = "std")]
+#[cold]
+fn init_mode() -> Mode {
+    let from_env = match std::env::var_os("DEBUG_UNWRAPS") {
+        Some(value) if value == "check" => CHECK,
+        Some(value) if value == "unchecked" => UNCHECKED,
+        _ => DEFAULT,
+    };
+    // Don't overwrite a mode set concurrently through `set_mode()`
+    match MODE.compare_exchange(UNINIT, from_env, Ordering::Relaxed, Ordering::Relaxed) {
+        Ok(_) if from_env == CHECK => Mode::Check,
+        Ok(_) => Mode::Unchecked,
+        Err(CHECK) => Mode::Check,
+        Err(_) => Mode::Unchecked,
+    }
+}
+
+/// Without `std` the mode starts out initialized, so this is never reached
+#[cfg(not(feature = "std"))]
+fn init_mode() -> Mode {
+    if CHECKS_BY_DEFAULT {
+        Mode::Check
+    } else {
+        Mode::Unchecked
+    }
+}
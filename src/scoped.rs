@@ -0,0 +1,53 @@
+use core::cell::Cell;
+
+std::thread_local! {
+    /// Whether checks are forced on or off for the current thread
+    static OVERRIDE: Cell<Option<bool>> = const { Cell::new(None) };
+}
+
+/// Returns the checking override of the current thread, if any
+#[inline]
+pub(crate) fn thread_override() -> Option<bool> {
+    OVERRIDE.try_with(Cell::get).ok().flatten()
+}
+
+/// Runs `f` with the debug checks enabled on the current thread.
+///
+/// This takes precedence over every other way of choosing whether to check,
+/// but does not affect other threads, including ones spawned by `f`. The
+/// previous state is restored once `f` returns or panics.
+pub fn with_checks<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    scoped(true, f)
+}
+
+/// Runs `f` with the debug checks disabled on the current thread.
+///
+/// This takes precedence over every other way of choosing whether to check,
+/// but does not affect other threads, including ones spawned by `f`. The
+/// previous state is restored once `f` returns or panics.
+pub fn without_checks<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    scoped(false, f)
+}
+
+fn scoped<F, R>(enabled: bool, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    /// Restores the previous override when dropped
+    struct Restore(Option<bool>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            OVERRIDE.with(|state| state.set(self.0));
+        }
+    }
+
+    let _restore = Restore(OVERRIDE.with(|state| state.replace(Some(enabled))));
+    f()
+}
@@ -0,0 +1,129 @@
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+use std::thread::{self, ThreadId};
+
+use crate::failure::{fail, FailureKind};
+use crate::mode::{checks_enabled, CheckState};
+
+/// A value which may only be used on the thread which created it, checked
+/// only in Debug mode.
+///
+/// The wrapper is always `Send` and `Sync` so it can be passed through APIs
+/// which require them while an invariant enforced elsewhere keeps every use
+/// on the owning thread. When debug assertions are enabled the creating
+/// thread is recorded and every access asserts that it happens there. In
+/// Release the wrapper has no overhead over `T`.
+pub struct DebugSingleThread<T: ?Sized> {
+    owner: CheckState<ThreadId>,
+    value: T,
+}
+
+// SAFETY: The caller of `new()` guaranteed that the value is only used and
+// dropped on the thread which created it
+unsafe impl<T: ?Sized> Send for DebugSingleThread<T> {}
+// SAFETY: As above, no other thread ever accesses the value
+unsafe impl<T: ?Sized> Sync for DebugSingleThread<T> {}
+
+impl<T> DebugSingleThread<T> {
+    /// Wraps `value`, making the current thread its owner.
+    ///
+    /// # Safety
+    /// Accessing or dropping the value on any other thread is undefined
+    /// behavior when debug assertions are disabled.
+    #[inline]
+    pub unsafe fn new(value: T) -> Self {
+        Self {
+            owner: CheckState::new_with(|| thread::current().id()),
+            value,
+        }
+    }
+
+    /// Consumes the wrapper, returning the wrapped value
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if called
+    /// from a thread other than the owner.
+    #[inline]
+    #[track_caller]
+    pub fn into_inner(self) -> T {
+        self.check_owner();
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `this` is never dropped, so the value is only read once
+        unsafe { ptr::read(&this.value) }
+    }
+}
+
+impl<T: ?Sized> DebugSingleThread<T> {
+    /// Returns a reference to the wrapped value
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if called
+    /// from a thread other than the owner.
+    #[inline]
+    #[track_caller]
+    pub fn get(&self) -> &T {
+        self.check_owner();
+        &self.value
+    }
+
+    /// Returns a mutable reference to the wrapped value
+    ///
+    /// # Panics
+    /// When debug assertions are enabled this function will panic if called
+    /// from a thread other than the owner.
+    #[inline]
+    #[track_caller]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.check_owner();
+        &mut self.value
+    }
+
+    /// Fails if the current thread is not the owner of the value
+    #[inline]
+    #[track_caller]
+    fn check_owner(&self) {
+        if checks_enabled() {
+            if let Some(&owner) = self.owner.get() {
+                let current = thread::current().id();
+                if current != owner {
+                    fail(
+                        FailureKind::Precondition,
+                        format_args!(
+                            "`DebugSingleThread` owned by {:?} was accessed from {:?}",
+                            owner, current
+                        ),
+                    )
+                }
+            }
+        }
+    }
+}
+
+impl<T: ?Sized> Deref for DebugSingleThread<T> {
+    type Target = T;
+
+    #[inline]
+    #[track_caller]
+    fn deref(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<T: ?Sized> DerefMut for DebugSingleThread<T> {
+    #[inline]
+    #[track_caller]
+    fn deref_mut(&mut self) -> &mut T {
+        self.get_mut()
+    }
+}
+
+impl<T: ?Sized> Drop for DebugSingleThread<T> {
+    #[inline]
+    fn drop(&mut self) {
+        // Panicking again while unwinding would abort the process
+        if checks_enabled() && !thread::panicking() {
+            self.check_owner();
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Debug only checked construction of slices from raw parts
+
+use core::mem;
+use core::ops::Range;
+use core::slice;
+
+use crate::failure::{fail, FailureKind};
+use crate::mode::checks_enabled;
+use crate::ptr::{check_bounds, check_deref};
+
+/// Forms a slice from a pointer and a length without checking the pointer
+/// and size only in Release mode.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic if `data` is
+/// null or not aligned for `T`, or if the slice would be larger than
+/// `isize::MAX` bytes.
+///
+/// # Safety
+/// The same requirements as for `core::slice::from_raw_parts()` apply.
+/// Violating the ones listed under Panics is undefined behavior when debug
+/// assertions are disabled.
+#[inline]
+#[track_caller]
+pub unsafe fn debug_slice_from_raw_parts<'a, T>(data: *const T, len: usize) -> &'a [T] {
+    if checks_enabled() {
+        check_raw_parts(data, len);
+    }
+    slice::from_raw_parts(data, len)
+}
+
+/// Forms a slice from a pointer and a length without checking the pointer,
+/// size and allocation bounds only in Release mode.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic if `data` is
+/// null or not aligned for `T`, if the slice would be larger than
+/// `isize::MAX` bytes or if it is not entirely inside of `bounds`.
+///
+/// # Safety
+/// The same requirements as for `core::slice::from_raw_parts()` apply.
+/// Violating the ones listed under Panics is undefined behavior when debug
+/// assertions are disabled.
+#[inline]
+#[track_caller]
+pub unsafe fn debug_slice_from_raw_parts_in<'a, T>(
+    data: *const T,
+    len: usize,
+    bounds: Range<*const T>,
+) -> &'a [T] {
+    if checks_enabled() {
+        check_raw_parts(data, len);
+        check_bounds(data, len, &bounds);
+    }
+    slice::from_raw_parts(data, len)
+}
+
+/// Forms a mutable slice from a pointer and a length without checking the
+/// pointer and size only in Release mode.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic if `data` is
+/// null or not aligned for `T`, or if the slice would be larger than
+/// `isize::MAX` bytes.
+///
+/// # Safety
+/// The same requirements as for `core::slice::from_raw_parts_mut()` apply.
+/// Violating the ones listed under Panics is undefined behavior when debug
+/// assertions are disabled.
+#[inline]
+#[track_caller]
+pub unsafe fn debug_slice_from_raw_parts_mut<'a, T>(data: *mut T, len: usize) -> &'a mut [T] {
+    if checks_enabled() {
+        check_raw_parts(data, len);
+    }
+    slice::from_raw_parts_mut(data, len)
+}
+
+/// Forms a mutable slice from a pointer and a length without checking the
+/// pointer, size and allocation bounds only in Release mode.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic if `data` is
+/// null or not aligned for `T`, if the slice would be larger than
+/// `isize::MAX` bytes or if it is not entirely inside of `bounds`.
+///
+/// # Safety
+/// The same requirements as for `core::slice::from_raw_parts_mut()` apply.
+/// Violating the ones listed under Panics is undefined behavior when debug
+/// assertions are disabled.
+#[inline]
+#[track_caller]
+pub unsafe fn debug_slice_from_raw_parts_mut_in<'a, T>(
+    data: *mut T,
+    len: usize,
+    bounds: Range<*mut T>,
+) -> &'a mut [T] {
+    if checks_enabled() {
+        check_raw_parts(data, len);
+        check_bounds(
+            data,
+            len,
+            &(bounds.start as *const T..bounds.end as *const T),
+        );
+    }
+    slice::from_raw_parts_mut(data, len)
+}
+
+/// Fails if `data` and `len` violate the documented preconditions of
+/// `slice::from_raw_parts()` which can be checked
+#[track_caller]
+fn check_raw_parts<T>(data: *const T, len: usize) {
+    check_deref(data);
+    match mem::size_of::<T>().checked_mul(len) {
+        Some(size) if size <= isize::MAX as usize => {}
+        _ => fail(
+            FailureKind::Precondition,
+            format_args!(
+                "slice of {} elements of {} bytes is larger than isize::MAX bytes",
+                len,
+                mem::size_of::<T>()
+            ),
+        ),
+    }
+}
@@ -0,0 +1,81 @@
+//! Debug only checked conversions from bytes to strings
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::str;
+
+use crate::failure::{fail, FailureKind};
+use crate::mode::checks_enabled;
+
+/// Converts a slice of bytes to a string slice without checking that it is
+/// valid UTF-8 only in Release mode.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic with the offset
+/// of the first invalid byte if `v` is not valid UTF-8.
+///
+/// # Safety
+/// Passing bytes which are not valid UTF-8 is undefined behavior when debug
+/// assertions are disabled.
+#[inline]
+#[track_caller]
+pub unsafe fn debug_from_utf8_unchecked(v: &[u8]) -> &str {
+    if checks_enabled() {
+        match str::from_utf8(v) {
+            Ok(s) => s,
+            Err(err) => fail(FailureKind::Precondition, format_args!("{}", err)),
+        }
+    } else {
+        str::from_utf8_unchecked(v)
+    }
+}
+
+/// Converts a mutable slice of bytes to a mutable string slice without
+/// checking that it is valid UTF-8 only in Release mode.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic with the offset
+/// of the first invalid byte if `v` is not valid UTF-8.
+///
+/// # Safety
+/// Passing bytes which are not valid UTF-8 is undefined behavior when debug
+/// assertions are disabled.
+#[inline]
+#[track_caller]
+pub unsafe fn debug_from_utf8_unchecked_mut(v: &mut [u8]) -> &mut str {
+    if checks_enabled() {
+        match str::from_utf8_mut(v) {
+            Ok(s) => s,
+            Err(err) => fail(FailureKind::Precondition, format_args!("{}", err)),
+        }
+    } else {
+        str::from_utf8_unchecked_mut(v)
+    }
+}
+
+/// Converts a vector of bytes to a `String` without checking that it is
+/// valid UTF-8 only in Release mode.
+///
+/// # Panics
+/// When debug assertions are enabled this function will panic with the offset
+/// of the first invalid byte if `bytes` is not valid UTF-8.
+///
+/// # Safety
+/// Passing bytes which are not valid UTF-8 is undefined behavior when debug
+/// assertions are disabled.
+#[cfg(feature = "alloc")]
+#[inline]
+#[track_caller]
+pub unsafe fn debug_string_from_utf8_unchecked(bytes: Vec<u8>) -> String {
+    if checks_enabled() {
+        match String::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(err) => fail(
+                FailureKind::Precondition,
+                format_args!("{}", err.utf8_error()),
+            ),
+        }
+    } else {
+        String::from_utf8_unchecked(bytes)
+    }
+}
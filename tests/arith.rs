@@ -0,0 +1,76 @@
+use debug_unwraps::DebugArithExt;
+
+macro_rules! test_valid_arith {
+    ($($t:ident)*) => {$(
+        #[test]
+        fn $t() {
+            let max = <$t>::MAX;
+            let bits = <$t>::BITS;
+            unsafe {
+                assert_eq!((max - 1).debug_add_unchecked(1), max);
+                assert_eq!(<$t>::MIN.debug_add_unchecked(0), <$t>::MIN);
+                assert_eq!((<$t>::MIN + 1).debug_sub_unchecked(1), <$t>::MIN);
+                assert_eq!((max / 2).debug_mul_unchecked(2), max - 1);
+                assert_eq!((1 as $t).debug_shl_unchecked(bits - 1), 1 << (bits - 1));
+                assert_eq!(max.debug_shr_unchecked(bits - 1), max >> (bits - 1));
+                assert_eq!((7 as $t).debug_div_unchecked(2), 3);
+                assert_eq!(<$t>::MIN.debug_div_unchecked(1), <$t>::MIN);
+            }
+        }
+    )*};
+}
+
+mod valid {
+    use super::*;
+
+    test_valid_arith!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "attempt to add with overflow")]
+fn add_overflow() {
+    unsafe { u8::MAX.debug_add_unchecked(1) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "attempt to subtract with overflow")]
+fn sub_overflow() {
+    unsafe { i32::MIN.debug_sub_unchecked(1) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn mul_overflow() {
+    unsafe { u64::MAX.debug_mul_unchecked(2) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "attempt to shift left with overflow")]
+fn shl_by_bits() {
+    unsafe { 1u32.debug_shl_unchecked(u32::BITS) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "attempt to shift right with overflow")]
+fn shr_by_bits() {
+    unsafe { 1i16.debug_shr_unchecked(i16::BITS) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "attempt to divide by zero")]
+fn div_by_zero() {
+    unsafe { 1usize.debug_div_unchecked(0) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn div_overflow() {
+    unsafe { i8::MIN.debug_div_unchecked(-1) };
+}
@@ -0,0 +1,74 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use debug_unwraps::DebugCell;
+
+/// Runs `f`, returning the message it panicked with
+fn panic_message(f: impl FnOnce()) -> String {
+    let payload = panic::catch_unwind(AssertUnwindSafe(f)).unwrap_err();
+    *payload.downcast::<String>().unwrap()
+}
+
+#[test]
+fn borrows_which_do_not_conflict() {
+    let cell = DebugCell::new(1u32);
+    unsafe {
+        let first = cell.borrow();
+        let second = cell.borrow();
+        assert_eq!(*first + *second, 2);
+        drop((first, second));
+
+        *cell.borrow_mut() += 1;
+        *cell.borrow_mut() += 1;
+        assert_eq!(*cell.borrow(), 3);
+    }
+    assert_eq!(cell.into_inner(), 3);
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "already mutably borrowed at tests/cell.rs:")]
+fn borrow_while_mutably_borrowed() {
+    let cell = DebugCell::new(1u32);
+    unsafe {
+        let _mutable = cell.borrow_mut();
+        let _shared = cell.borrow();
+    }
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "already borrowed at tests/cell.rs:")]
+fn borrow_mut_while_borrowed() {
+    let cell = DebugCell::new(1u32);
+    unsafe {
+        let _shared = cell.borrow();
+        let _mutable = cell.borrow_mut();
+    }
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+fn conflict_names_the_first_borrow() {
+    let cell = DebugCell::new(1u32);
+    let first = unsafe { cell.borrow() };
+    let line = line!() - 1;
+    let second = unsafe { cell.borrow() };
+
+    let message = panic_message(|| unsafe { drop(cell.borrow_mut()) });
+    let expected = format!("already borrowed at {}:{}:", file!(), line);
+    assert!(message.starts_with(&expected), "{}", message);
+    drop((first, second));
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+fn conflict_names_the_mutable_borrow() {
+    let cell = DebugCell::new(1u32);
+    let mutable = unsafe { cell.borrow_mut() };
+    let line = line!() - 1;
+
+    let message = panic_message(|| unsafe { drop(cell.borrow()) });
+    let expected = format!("already mutably borrowed at {}:{}:", file!(), line);
+    assert!(message.starts_with(&expected), "{}", message);
+    drop(mutable);
+}
@@ -0,0 +1,51 @@
+use debug_unwraps::char::{debug_char_from_digit_unchecked, debug_char_from_u32_unchecked};
+
+#[test]
+fn valid_code_points() {
+    for i in [0, 0x41, 0xd7ff, 0xe000, 0x10ffff] {
+        assert_eq!(
+            unsafe { debug_char_from_u32_unchecked(i) },
+            char::from_u32(i).unwrap()
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "invalid char: 0xd800 is a surrogate code point")]
+fn surrogate() {
+    unsafe { debug_char_from_u32_unchecked(0xd800) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "invalid char: 0x110000 is greater than char::MAX")]
+fn above_char_max() {
+    unsafe { debug_char_from_u32_unchecked(0x110000) };
+}
+
+#[test]
+fn valid_digits() {
+    for radix in 2..=36 {
+        for num in 0..radix {
+            assert_eq!(
+                unsafe { debug_char_from_digit_unchecked(num, radix) },
+                char::from_digit(num, radix).unwrap()
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "invalid radix 37: must be between 2 and 36")]
+fn invalid_radix() {
+    unsafe { debug_char_from_digit_unchecked(1, 37) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "invalid digit 10 for radix 10")]
+fn invalid_digit() {
+    unsafe { debug_char_from_digit_unchecked(10, 10) };
+}
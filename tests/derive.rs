@@ -0,0 +1,63 @@
+use debug_unwraps::DebugFromRepr;
+
+#[derive(DebugFromRepr, Debug, PartialEq)]
+#[repr(u8)]
+enum Tag {
+    Int = 1,
+    Float = 2,
+}
+
+#[derive(DebugFromRepr, Debug, PartialEq)]
+#[repr(i16)]
+enum Sparse {
+    Low = -300,
+    Zero = 0,
+    High = 1000,
+}
+
+mod reexport {
+    pub use ::debug_unwraps as renamed;
+}
+
+#[derive(DebugFromRepr, Debug, PartialEq)]
+#[debug_unwraps(crate = "crate::reexport::renamed")]
+#[repr(u32)]
+enum Renamed {
+    One = 1,
+}
+
+#[test]
+fn valid_discriminant() {
+    unsafe {
+        assert_eq!(Tag::debug_from_repr_unchecked(1), Tag::Int);
+        assert_eq!(Tag::debug_from_repr_unchecked(2), Tag::Float);
+    }
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "3 is not a discriminant of `Tag`")]
+fn invalid_discriminant() {
+    unsafe { Tag::debug_from_repr_unchecked(3) };
+}
+
+#[test]
+fn non_contiguous_discriminants() {
+    unsafe {
+        assert_eq!(Sparse::debug_from_repr_unchecked(-300), Sparse::Low);
+        assert_eq!(Sparse::debug_from_repr_unchecked(0), Sparse::Zero);
+        assert_eq!(Sparse::debug_from_repr_unchecked(1000), Sparse::High);
+    }
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "1 is not a discriminant of `Sparse`")]
+fn gap_between_discriminants() {
+    unsafe { Sparse::debug_from_repr_unchecked(1) };
+}
+
+#[test]
+fn renamed_crate_path() {
+    unsafe { assert_eq!(Renamed::debug_from_repr_unchecked(1), Renamed::One) };
+}
@@ -0,0 +1,40 @@
+use std::panic;
+use std::thread;
+
+use debug_unwraps::DebugExclusive;
+
+#[test]
+fn enter_sequentially_from_threads() {
+    let counter = DebugExclusive::new(0u32);
+    for _ in 0..4 {
+        thread::scope(|s| {
+            s.spawn(|| unsafe { *counter.enter() += 1 });
+        });
+    }
+    assert_eq!(counter.into_inner(), 4);
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "critical section was already entered at tests/exclusive.rs:")]
+fn enter_twice_on_one_thread() {
+    let value = DebugExclusive::new(0u32);
+    unsafe {
+        let _first = value.enter();
+        let _second = value.enter();
+    }
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "critical section was already entered at tests/exclusive.rs:")]
+fn enter_from_another_thread() {
+    let value = DebugExclusive::new(0u32);
+    let _guard = unsafe { value.enter() };
+    thread::scope(|s| {
+        let result = s.spawn(|| unsafe { *value.enter() += 1 }).join();
+        if let Err(payload) = result {
+            panic::resume_unwind(payload);
+        }
+    });
+}
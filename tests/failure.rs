@@ -0,0 +1,82 @@
+use std::cell::RefCell;
+use std::panic;
+use std::sync::Once;
+
+use debug_unwraps::{debug_assume, set_failure_handler, DebugUnwrapExt, Failure, FailureKind};
+
+struct Recorded {
+    kind: FailureKind,
+    message: String,
+    line: u32,
+}
+
+std::thread_local! {
+    static RECORDED: RefCell<Vec<Recorded>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Records failures of the current thread instead of panicking, as the
+/// handler is shared by every test in this file
+fn record(failure: &Failure<'_>) {
+    RECORDED.with(|recorded| {
+        recorded.borrow_mut().push(Recorded {
+            kind: failure.kind(),
+            message: failure.message().to_string(),
+            line: failure.location().line(),
+        })
+    });
+}
+
+fn recorded() -> Vec<Recorded> {
+    static REGISTER: Once = Once::new();
+    REGISTER.call_once(|| set_failure_handler(record));
+    RECORDED.with(|recorded| recorded.take())
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+fn returning_handler_continues_with_fallback() {
+    recorded();
+    let line = line!() + 1;
+    assert_eq!(None.debug_unwrap_or(7), 7);
+    assert_eq!(Err::<u32, _>("oops").debug_unwrap_or_default(), 0);
+
+    let recorded = recorded();
+    assert_eq!(recorded.len(), 2);
+    assert_eq!(recorded[0].kind, FailureKind::None);
+    assert_eq!(recorded[0].line, line);
+    assert_eq!(recorded[1].kind, FailureKind::Err);
+    assert_eq!(
+        recorded[1].message,
+        "called `Result::unwrap()` on an `Err` value: \"oops\""
+    );
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+fn handler_receives_failure() {
+    recorded();
+    let line = line!() + 1;
+    let result = panic::catch_unwind(|| unsafe { None::<u32>.debug_expect_unchecked("missing") });
+    assert!(result.is_err());
+
+    let recorded = recorded();
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].kind, FailureKind::None);
+    assert_eq!(recorded[0].message, "missing");
+    assert_eq!(recorded[0].line, line);
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+fn handler_receives_macro_failure() {
+    recorded();
+    let line = line!() + 1;
+    let result = panic::catch_unwind(|| unsafe { debug_assume!(1 + 1 == 3) });
+    assert!(result.is_err());
+
+    let recorded = recorded();
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].kind, FailureKind::Assumption);
+    assert_eq!(recorded[0].message, "assumption failed: 1 + 1 == 3");
+    assert_eq!(recorded[0].line, line);
+}
@@ -0,0 +1,67 @@
+use std::cell::RefCell;
+
+use debug_unwraps::{debug_expect, debug_expect_err, debug_unwrap};
+
+#[test]
+fn expect_valid_values() {
+    unsafe {
+        assert_eq!(debug_expect!(Some(1), "missing"), 1);
+        assert_eq!(debug_expect!(Ok::<_, ()>(2), "failed"), 2);
+        assert_eq!(debug_expect_err!(Err::<(), _>(3), "succeeded"), 3);
+    }
+}
+
+#[test]
+fn expect_keeps_temporaries_alive() {
+    let values = RefCell::new(vec![1, 2]);
+    let first = unsafe { *debug_expect!(values.borrow().first(), "empty") };
+    let error = unsafe { *debug_expect_err!(Err::<(), _>(&values.borrow()[1]), "ok") };
+    assert_eq!((first, error), (1, 2));
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "node 7 has no parent")]
+fn expect_none() {
+    let id = 7;
+    unsafe { debug_expect!(None::<u32>, "node {} has no parent", id) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "parsing 2 digits: ParseIntError")]
+fn expect_err() {
+    unsafe { debug_expect!("x1".parse::<u32>(), "parsing {} digits", 2) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "expected an error: 5")]
+fn expect_err_ok() {
+    unsafe { debug_expect_err!(Ok::<_, ()>(5), "expected an {}", "error") };
+}
+
+#[test]
+fn unwrap_valid_values() {
+    let values = RefCell::new(vec![1, 2]);
+    unsafe {
+        assert_eq!(debug_unwrap!(Some(1)), 1);
+        assert_eq!(debug_unwrap!(Ok::<_, ()>(2)), 2);
+        assert_eq!(*debug_unwrap!(values.borrow().last()), 2);
+    }
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "`opt` was None")]
+fn unwrap_none() {
+    let opt = None::<u32>;
+    unsafe { debug_unwrap!(opt) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "`\"x1\".parse::<u32>()` was Err: ParseIntError")]
+fn unwrap_err() {
+    unsafe { debug_unwrap!("x1".parse::<u32>()) };
+}
@@ -0,0 +1,78 @@
+use std::rc::Rc;
+
+use debug_unwraps::DebugMaybeUninit;
+
+#[test]
+fn initialized_values() {
+    let value = DebugMaybeUninit::new(1u32);
+    assert_eq!(unsafe { value.assume_init() }, 1);
+
+    let mut value = DebugMaybeUninit::uninit();
+    *value.write(2u32) += 1;
+    unsafe {
+        assert_eq!(*value.assume_init_ref(), 3);
+        *value.assume_init_mut() += 1;
+        assert_eq!(value.assume_init_read(), 4);
+    }
+}
+
+#[test]
+fn initialized_through_pointer() {
+    let mut value = DebugMaybeUninit::<u32>::uninit();
+    unsafe {
+        value.as_mut_ptr().write(5);
+        value.set_init();
+        assert_eq!(value.as_ptr().read(), 5);
+        assert_eq!(value.assume_init(), 5);
+    }
+}
+
+#[test]
+fn drop_in_place() {
+    let shared = Rc::new(());
+    let mut value = DebugMaybeUninit::new(Rc::clone(&shared));
+    assert_eq!(Rc::strong_count(&shared), 2);
+    unsafe { value.assume_init_drop() };
+    assert_eq!(Rc::strong_count(&shared), 1);
+
+    // The value can be initialized again after it was dropped
+    value.write(Rc::clone(&shared));
+    drop(unsafe { value.assume_init() });
+    assert_eq!(Rc::strong_count(&shared), 1);
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "called `DebugMaybeUninit::assume_init()` on an uninitialized value")]
+fn assume_init_uninit() {
+    let value = DebugMaybeUninit::<u32>::uninit();
+    unsafe { value.assume_init() };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "called `DebugMaybeUninit::assume_init_ref()` on an uninitialized value")]
+fn assume_init_ref_uninit() {
+    let value = DebugMaybeUninit::<u32>::uninit();
+    unsafe { value.assume_init_ref() };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "called `DebugMaybeUninit::write()` on an initialized value")]
+fn write_twice() {
+    let mut value = DebugMaybeUninit::uninit();
+    value.write(1u32);
+    value.write(2u32);
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "called `DebugMaybeUninit::assume_init()` on an uninitialized value")]
+fn assume_init_after_drop() {
+    let mut value = DebugMaybeUninit::new(Rc::new(()));
+    unsafe {
+        value.assume_init_drop();
+        value.assume_init();
+    }
+}
@@ -0,0 +1,161 @@
+use std::ptr;
+
+use debug_unwraps::ptr::{
+    debug_copy_nonoverlapping, debug_mut_unchecked, debug_mut_unchecked_in,
+    debug_nonnull_unchecked, debug_ptr_read_unchecked, debug_ptr_read_unchecked_in,
+    debug_ref_unchecked, debug_ref_unchecked_in,
+};
+
+/// Returns a pointer into `data` which is not aligned for `u32`
+fn misaligned(data: &[u32; 4]) -> *const u32 {
+    data.as_ptr().cast::<u8>().wrapping_add(1).cast()
+}
+
+#[test]
+fn valid_pointers() {
+    let mut data = [1u32, 2, 3, 4];
+    let bounds = data.as_ptr_range();
+    unsafe {
+        assert_eq!(
+            debug_nonnull_unchecked(data.as_mut_ptr()).as_ptr(),
+            data.as_mut_ptr()
+        );
+        assert_eq!(debug_ptr_read_unchecked(&data[1]), 2);
+        assert_eq!(
+            debug_ptr_read_unchecked_in(data.as_ptr().add(3), bounds.clone()),
+            4
+        );
+        assert_eq!(*debug_ref_unchecked(data.as_ptr()), 1);
+        assert_eq!(*debug_ref_unchecked_in(data.as_ptr().add(2), bounds), 3);
+        *debug_mut_unchecked(data.as_mut_ptr()) = 5;
+        let bounds = data.as_mut_ptr_range();
+        *debug_mut_unchecked_in(data.as_mut_ptr().add(1), bounds) = 6;
+    }
+    assert_eq!(data, [5, 6, 3, 4]);
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "called `debug_nonnull_unchecked()` with a null pointer")]
+fn nonnull_null() {
+    unsafe { debug_nonnull_unchecked(ptr::null_mut::<u32>()) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "null pointer dereference")]
+fn read_null() {
+    unsafe { debug_ptr_read_unchecked(ptr::null::<u32>()) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "misaligned pointer dereference")]
+fn read_misaligned() {
+    let data = [0u32; 4];
+    unsafe { debug_ptr_read_unchecked(misaligned(&data)) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "null pointer dereference")]
+fn ref_null() {
+    unsafe { debug_ref_unchecked(ptr::null::<u32>()) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "misaligned pointer dereference")]
+fn mut_misaligned() {
+    let data = [0u32; 4];
+    unsafe { debug_mut_unchecked(misaligned(&data).cast_mut()) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "is outside of the valid range")]
+fn read_past_bounds() {
+    let data = [1u32, 2, 3];
+    unsafe { debug_ptr_read_unchecked_in(data.as_ptr().add(3), data.as_ptr_range()) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "is outside of the valid range")]
+fn ref_before_bounds() {
+    let data = [1u32, 2, 3];
+    let bounds = data[1..].as_ptr_range();
+    unsafe { debug_ref_unchecked_in(data.as_ptr(), bounds) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "is outside of the valid range")]
+fn mut_past_bounds() {
+    let mut data = [1u32, 2, 3];
+    let bounds = data[..2].as_mut_ptr_range();
+    unsafe { debug_mut_unchecked_in(data.as_mut_ptr().add(2), bounds) };
+}
+
+#[test]
+fn copy_between_disjoint_regions() {
+    let mut data = [1u32, 2, 3, 4];
+    let mut other = [0u32; 2];
+    unsafe {
+        debug_copy_nonoverlapping(data.as_ptr(), other.as_mut_ptr(), 2);
+        // Adjacent regions do not overlap
+        debug_copy_nonoverlapping(data.as_ptr(), data.as_mut_ptr().add(2), 2);
+        // Zero-sized copies never overlap
+        debug_copy_nonoverlapping(data.as_ptr(), data.as_mut_ptr(), 0);
+    }
+    assert_eq!(other, [1, 2]);
+    assert_eq!(data, [1, 2, 1, 2]);
+}
+
+#[test]
+fn copy_nothing_between_null_pointers() {
+    unsafe { debug_copy_nonoverlapping(ptr::null::<u32>(), ptr::null_mut(), 0) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "misaligned pointer dereference")]
+fn copy_nothing_misaligned() {
+    let data = [0u32; 4];
+    let mut other = [0u32; 4];
+    unsafe { debug_copy_nonoverlapping(misaligned(&data), other.as_mut_ptr(), 0) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "overlaps")]
+fn copy_overlapping() {
+    let mut data = [1u32, 2, 3, 4];
+    unsafe { debug_copy_nonoverlapping(data.as_ptr(), data.as_mut_ptr().add(1), 2) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "misaligned pointer dereference")]
+fn copy_misaligned() {
+    let data = [0u32; 4];
+    let mut other = [0u32; 4];
+    unsafe { debug_copy_nonoverlapping(misaligned(&data), other.as_mut_ptr(), 1) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "null pointer dereference")]
+fn copy_to_null() {
+    let data = [0u32; 4];
+    unsafe { debug_copy_nonoverlapping(data.as_ptr(), ptr::null_mut(), 1) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "overflows isize::MAX bytes")]
+fn copy_overflowing() {
+    let data = [0u32; 4];
+    let mut other = [0u32; 4];
+    unsafe { debug_copy_nonoverlapping(data.as_ptr(), other.as_mut_ptr(), usize::MAX / 2) };
+}
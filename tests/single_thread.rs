@@ -0,0 +1,54 @@
+use std::panic;
+use std::rc::Rc;
+use std::sync::mpsc;
+use std::thread;
+
+use debug_unwraps::DebugSingleThread;
+
+#[test]
+fn access_from_owner() {
+    let mut value = unsafe { DebugSingleThread::new(Rc::new(1u32)) };
+    assert_eq!(**value.get(), 1);
+    *value.get_mut() = Rc::new(2);
+    assert_eq!(**value, 2);
+    assert_eq!(*value.into_inner(), 2);
+}
+
+#[test]
+fn send_back_to_owner() {
+    let (to_worker, worker) = mpsc::channel();
+    let (to_owner, owner) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        // The worker only passes the value on without accessing it
+        let value: DebugSingleThread<Rc<u32>> = worker.recv().unwrap();
+        to_owner.send(value).unwrap();
+    });
+    to_worker
+        .send(unsafe { DebugSingleThread::new(Rc::new(1u32)) })
+        .unwrap();
+    let value = owner.recv().unwrap();
+    handle.join().unwrap();
+    assert_eq!(**value, 1);
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "was accessed from")]
+fn access_from_other_thread() {
+    let value = unsafe { DebugSingleThread::new(1u32) };
+    thread::scope(|s| {
+        if let Err(payload) = s.spawn(|| *value.get()).join() {
+            panic::resume_unwind(payload);
+        }
+    });
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "was accessed from")]
+fn into_inner_on_other_thread() {
+    let value = unsafe { DebugSingleThread::new(1u32) };
+    if let Err(payload) = thread::spawn(move || value.into_inner()).join() {
+        panic::resume_unwind(payload);
+    }
+}
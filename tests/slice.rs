@@ -0,0 +1,65 @@
+use std::ptr;
+
+use debug_unwraps::slice::{
+    debug_slice_from_raw_parts, debug_slice_from_raw_parts_in, debug_slice_from_raw_parts_mut,
+    debug_slice_from_raw_parts_mut_in,
+};
+
+#[test]
+fn valid_slices() {
+    let mut data = [1u32, 2, 3, 4];
+    let bounds = data.as_ptr_range();
+    unsafe {
+        assert_eq!(debug_slice_from_raw_parts(data.as_ptr(), 4), [1, 2, 3, 4]);
+        assert_eq!(debug_slice_from_raw_parts(data.as_ptr().add(4), 0), []);
+        assert_eq!(
+            debug_slice_from_raw_parts_in(data.as_ptr().add(1), 3, bounds),
+            [2, 3, 4]
+        );
+        debug_slice_from_raw_parts_mut(data.as_mut_ptr(), 2).fill(5);
+        let bounds = data.as_mut_ptr_range();
+        debug_slice_from_raw_parts_mut_in(data.as_mut_ptr().add(2), 2, bounds).fill(6);
+    }
+    assert_eq!(data, [5, 5, 6, 6]);
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "null pointer dereference")]
+fn null_data() {
+    unsafe { debug_slice_from_raw_parts(ptr::null::<u32>(), 0) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "misaligned pointer dereference")]
+fn misaligned_data() {
+    let mut data = [0u32; 4];
+    let misaligned = data.as_mut_ptr().cast::<u8>().wrapping_add(2).cast::<u32>();
+    unsafe { debug_slice_from_raw_parts_mut(misaligned, 1) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "is larger than isize::MAX bytes")]
+fn too_large() {
+    let data = [0u32; 4];
+    unsafe { debug_slice_from_raw_parts(data.as_ptr(), usize::MAX / 4) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "is outside of the valid range")]
+fn past_bounds() {
+    let data = [1u32, 2, 3];
+    unsafe { debug_slice_from_raw_parts_in(data.as_ptr().add(1), 3, data.as_ptr_range()) };
+}
+
+#[test]
+#[cfg_attr(not(debug_unwraps_checks), ignore)]
+#[should_panic(expected = "is outside of the valid range")]
+fn mut_past_bounds() {
+    let mut data = [1u32, 2, 3];
+    let bounds = data[..2].as_mut_ptr_range();
+    unsafe { debug_slice_from_raw_parts_mut_in(data.as_mut_ptr(), 3, bounds) };
+}